@@ -0,0 +1,111 @@
+//! Rendering benchmarks for large boxes.
+//!
+//! To measure against an older commit on the same machine, check it out and run
+//! `cargo bench --bench render -- --save-baseline before`, then run
+//! `cargo bench --bench render -- --baseline before` on the newer commit.
+use std::io;
+
+use bauxite::{AnsiColorCode, BoxBuilder, ColorSupport, Gradient, RgbColor, Section, Shadow};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// A log-like message of `lines` lines, some long enough to wrap.
+fn message(lines: usize) -> String {
+    (0..lines)
+        .map(|index| match index % 4 {
+            0 => format!("{:>6} INFO  request served in {}ms", index, index % 97),
+            1 => format!(
+                "{:>6} DEBUG cache lookup for key user:{} missed, falling back to the primary \
+                 database and warming the cache for the next request",
+                index, index
+            ),
+            2 => format!(
+                "{:>6} WARN  \x1B[33mslow query\x1B[0m on shard {}",
+                index,
+                index % 8
+            ),
+            _ => format!("{:>6} INFO  日本語のログ {}", index, index),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn plain(message: &str) -> BoxBuilder {
+    BoxBuilder::from(message).max_width(100)
+}
+
+fn styled(message: &str) -> BoxBuilder {
+    BoxBuilder::from(message)
+        .max_width(100)
+        .title("server log")
+        .color(AnsiColorCode::Cyan)
+        .background(AnsiColorCode::Black)
+        .color_support(ColorSupport::Always)
+        .margin((1, 2))
+        .shadow(Shadow::light())
+        .section(Section::new("end of log"))
+}
+
+fn gradient(message: &str) -> BoxBuilder {
+    BoxBuilder::from(message)
+        .max_width(100)
+        .gradient(Gradient::vertical(vec![
+            RgbColor {
+                red: 255,
+                green: 0,
+                blue: 0,
+            },
+            RgbColor {
+                red: 0,
+                green: 0,
+                blue: 255,
+            },
+        ]))
+        .color_support(ColorSupport::Always)
+}
+
+type Builder = fn(&str) -> BoxBuilder;
+
+fn bench_render(criterion: &mut Criterion) {
+    let builders: [(&str, Builder); 3] =
+        [("plain", plain), ("styled", styled), ("gradient", gradient)];
+    let mut boxes = Vec::new();
+    for lines in [1_000, 5_000] {
+        let message = message(lines);
+        for (name, builder) in builders.iter() {
+            boxes.push((BenchmarkId::new(*name, lines), builder(&message)));
+        }
+    }
+
+    let mut group = criterion.benchmark_group("to_string");
+    for (id, boxed) in boxes.iter() {
+        group.bench_with_input(id.clone(), boxed, |bencher, boxed| {
+            bencher.iter(|| black_box(boxed.to_string()))
+        });
+    }
+    group.finish();
+
+    // reuse one buffer so only the rendering itself allocates
+    let mut group = criterion.benchmark_group("render_to");
+    let mut rendered = String::new();
+    for (id, boxed) in boxes.iter() {
+        group.bench_with_input(id.clone(), boxed, |bencher, boxed| {
+            bencher.iter(|| {
+                rendered.clear();
+                boxed.render_to(&mut rendered).unwrap();
+                black_box(rendered.len())
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = criterion.benchmark_group("write_to");
+    for (id, boxed) in boxes.iter() {
+        group.bench_with_input(id.clone(), boxed, |bencher, boxed| {
+            bencher.iter(|| boxed.write_to(&mut io::sink()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
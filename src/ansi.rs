@@ -0,0 +1,228 @@
+//! Parsing of ANSI escape sequences embedded in the message.
+//!
+//! Escape sequences take up no room on the terminal, so they are skipped when
+//! measuring text and never split when wrapping it.
+use std::fmt;
+
+use crate::color::RESET_CODE;
+
+const ESCAPE: char = '\x1B';
+
+/// A piece of text that is either printable or a single escape sequence.
+#[derive(Debug, PartialEq)]
+pub enum Segment<'a> {
+    Text(&'a str),
+    Escape(&'a str),
+}
+
+impl<'a> Segment<'a> {
+    /// The text of the segment as it appears in the source string.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Segment::Text(text) | Segment::Escape(text) => text,
+        }
+    }
+}
+
+/// Iterator over the printable text and escape sequences of a string.
+pub struct Segments<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        if self.remaining.starts_with(ESCAPE) {
+            let (escape, rest) = self.remaining.split_at(escape_len(self.remaining));
+            self.remaining = rest;
+            Some(Segment::Escape(escape))
+        } else {
+            let end = self.remaining.find(ESCAPE).unwrap_or(self.remaining.len());
+            let (text, rest) = self.remaining.split_at(end);
+            self.remaining = rest;
+            Some(Segment::Text(text))
+        }
+    }
+}
+
+/// Split text into printable text and escape sequences.
+pub fn segments(text: &str) -> Segments<'_> {
+    Segments { remaining: text }
+}
+
+/// Byte length of the escape sequence at the start of the text.
+///
+/// Handles CSI sequences (`ESC [ ... final`), OSC sequences (`ESC ] ...` ended by BEL
+/// or `ESC \`) and two character escapes. An unterminated sequence runs to the end
+/// of the text.
+fn escape_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|byte| (0x40..=0x7E).contains(byte))
+            .map_or(bytes.len(), |index| index + 3),
+        Some(b']') => {
+            let body = &text[2..];
+            match (body.find('\x07'), body.find("\x1B\\")) {
+                (Some(bell), Some(st)) if st < bell => st + 4,
+                (Some(bell), _) => bell + 3,
+                (None, Some(st)) => st + 4,
+                (None, None) => bytes.len(),
+            }
+        }
+        Some(_) => 1 + text[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Whether the escape sequence is an SGR (Select Graphic Rendition) sequence that
+/// sets colors and text styles.
+fn is_sgr(escape: &str) -> bool {
+    escape.starts_with("\x1B[") && escape.ends_with('m')
+}
+
+/// Whether the SGR sequence starts by resetting every style, like `ESC[0m` or
+/// `ESC[0;31m`.
+fn starts_with_reset(sgr: &str) -> bool {
+    let first = sgr[2..sgr.len() - 1].split(';').next().unwrap_or("");
+    first.chars().all(|c| c == '0')
+}
+
+/// Whether the SGR sequence does nothing but reset every style.
+fn is_reset(sgr: &str) -> bool {
+    sgr[2..sgr.len() - 1].chars().all(|c| c == '0' || c == ';')
+}
+
+/// Start the text in `style` and start the style again after every reset in the
+/// text, so the text's own resets can't turn it off.
+pub fn restyle(text: &str, style: &str) -> String {
+    let mut restyled = String::from(style);
+    // writing to a String can't fail
+    let _ = write_restyled(&mut restyled, text, style);
+    restyled
+}
+
+/// Write the text, starting `style` again after every reset in the text. The style
+/// isn't written before the text.
+pub fn write_restyled<W: fmt::Write>(out: &mut W, text: &str, style: &str) -> fmt::Result {
+    for segment in segments(text) {
+        out.write_str(segment.as_str())?;
+        if let Segment::Escape(escape) = segment {
+            if is_sgr(escape) && starts_with_reset(escape) {
+                out.write_str(style)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Make every row carry its own styles.
+///
+/// Styles still active at the end of a row are reset there and started again at the
+/// beginning of the next row, so colors in the message never bleed into the border.
+pub fn carry_styles(mut rows: Vec<String>) -> Vec<String> {
+    let mut active: Vec<String> = Vec::new();
+    for row in rows.iter_mut() {
+        let carried = active.concat();
+        if row.contains('\x1B') {
+            for segment in segments(row) {
+                match segment {
+                    Segment::Escape(escape) if is_sgr(escape) => {
+                        if starts_with_reset(escape) {
+                            active.clear();
+                        }
+                        if !is_reset(escape) {
+                            active.push(String::from(escape));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !carried.is_empty() {
+            row.insert_str(0, &carried);
+        }
+        if !active.is_empty() {
+            row.push_str(RESET_CODE);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        let text = "a\x1B[31mred\x1B[0m";
+        let expected = vec![
+            Segment::Text("a"),
+            Segment::Escape("\x1B[31m"),
+            Segment::Text("red"),
+            Segment::Escape("\x1B[0m"),
+        ];
+        assert_eq!(expected, segments(text).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_csi_sequences() {
+        assert_eq!(escape_len("\x1B[38;2;1;2;3mtext"), 13);
+        assert_eq!(escape_len("\x1B[2Ktext"), 4);
+        assert_eq!(escape_len("\x1B[38;5"), 6);
+    }
+
+    #[test]
+    fn test_osc_sequences() {
+        let link = "\x1B]8;;https://example.com\x1B\\link\x1B]8;;\x1B\\";
+        let expected = vec![
+            Segment::Escape("\x1B]8;;https://example.com\x1B\\"),
+            Segment::Text("link"),
+            Segment::Escape("\x1B]8;;\x1B\\"),
+        ];
+        assert_eq!(expected, segments(link).collect::<Vec<_>>());
+        assert_eq!(escape_len("\x1B]0;title\x07text"), 10);
+    }
+
+    #[test]
+    fn test_two_character_escape() {
+        assert_eq!(escape_len("\x1B(text"), 2);
+        assert_eq!(escape_len("\x1B"), 1);
+    }
+
+    #[test]
+    fn test_restyle() {
+        assert_eq!(restyle("a", "\x1B[1m"), "\x1B[1ma");
+        assert_eq!(
+            restyle("a\x1B[0;31mb\x1B[0mc", "\x1B[1m"),
+            "\x1B[1ma\x1B[0;31m\x1B[1mb\x1B[0m\x1B[1mc"
+        );
+    }
+
+    #[test]
+    fn test_carry_styles() {
+        let rows = vec![
+            String::from("plain \x1B[1m\x1B[31mred"),
+            String::from("still red"),
+            String::from("red\x1B[0m plain"),
+            String::from("plain \x1B[0;32mgreen"),
+            String::from("green\x1B[m"),
+            String::from("plain"),
+        ];
+        let expected = vec![
+            "plain \x1B[1m\x1B[31mred\x1B[0m",
+            "\x1B[1m\x1B[31mstill red\x1B[0m",
+            "\x1B[1m\x1B[31mred\x1B[0m plain",
+            "plain \x1B[0;32mgreen\x1B[0m",
+            "\x1B[0;32mgreen\x1B[m",
+            "plain",
+        ];
+        assert_eq!(expected, carry_styles(rows));
+    }
+}
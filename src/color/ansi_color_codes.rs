@@ -1,4 +1,5 @@
 /// Simple ANSI predefined codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AnsiColorCode {
     Black,
     Red,
@@ -0,0 +1,300 @@
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::rgb_color::RgbColor;
+use super::support::ColorLevel;
+use super::{Color, RESET_CODE};
+use crate::width;
+
+/// Which way the colors of a gradient run across the border.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Direction {
+    Horizontal,
+    Vertical,
+    Perimeter,
+}
+
+/// A border that blends smoothly between two or more RGB colors.
+///
+/// Every glyph of the border is drawn in its own color. When the terminal can't
+/// display RGB colors each glyph falls back to the nearest color it can.
+/// ```
+/// use bauxite::{BoxBuilder, Gradient, RgbColor};
+///
+/// let rainbow = Gradient::perimeter(vec![
+///     RgbColor { red: 255, green: 0, blue: 0 },
+///     RgbColor { red: 0, green: 255, blue: 0 },
+///     RgbColor { red: 0, green: 0, blue: 255 },
+/// ]);
+/// println!("{}", BoxBuilder::from("Release 1.0").gradient(rainbow));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gradient {
+    stops: Vec<RgbColor>,
+    direction: Direction,
+}
+
+impl Gradient {
+    /// Blend the colors from the left edge of the box to the right edge.
+    pub fn horizontal(stops: Vec<RgbColor>) -> Gradient {
+        Gradient {
+            stops,
+            direction: Direction::Horizontal,
+        }
+    }
+
+    /// Blend the colors from the top edge of the box to the bottom edge.
+    pub fn vertical(stops: Vec<RgbColor>) -> Gradient {
+        Gradient {
+            stops,
+            direction: Direction::Vertical,
+        }
+    }
+
+    /// Blend the colors clockwise around the border starting from the top left
+    /// corner, returning to the first color at the end so there is no seam.
+    pub fn perimeter(stops: Vec<RgbColor>) -> Gradient {
+        Gradient {
+            stops,
+            direction: Direction::Perimeter,
+        }
+    }
+
+    /// Color of the border glyph at column `x` and row `y` of a box `width` columns
+    /// wide and `height` rows tall. There is no color when the gradient has no stops.
+    pub fn color_at(&self, x: usize, y: usize, width: usize, height: usize) -> Option<RgbColor> {
+        let last_x = width.saturating_sub(1);
+        let last_y = height.saturating_sub(1);
+        match self.direction {
+            Direction::Horizontal => interpolate(&self.stops, x, last_x),
+            Direction::Vertical => interpolate(&self.stops, y, last_y),
+            Direction::Perimeter => {
+                let position = if y == 0 {
+                    x
+                } else if x == last_x && y < last_y {
+                    last_x + y
+                } else if y == last_y {
+                    last_x + last_y + (last_x - x)
+                } else {
+                    2 * last_x + last_y + (last_y - y)
+                };
+                if self.stops.is_empty() {
+                    return None;
+                }
+                // blend back into the first color after the last one
+                let count = self.stops.len();
+                let stop = |index: usize| self.stops[index % count];
+                blend(count + 1, stop, position, 2 * (last_x + last_y))
+            }
+        }
+    }
+}
+
+/// Blend linearly between evenly spaced stops at `position` out of `length`.
+fn interpolate(stops: &[RgbColor], position: usize, length: usize) -> Option<RgbColor> {
+    blend(stops.len(), |index| stops[index], position, length)
+}
+
+/// Blend linearly between `count` evenly spaced stops, each given by `stop`, at
+/// `position` out of `length`.
+fn blend<F: Fn(usize) -> RgbColor>(
+    count: usize,
+    stop: F,
+    position: usize,
+    length: usize,
+) -> Option<RgbColor> {
+    if count == 0 {
+        return None;
+    }
+    if count < 2 || length == 0 {
+        return Some(stop(0));
+    }
+
+    let segments = count - 1;
+    let scaled = position.min(length) * segments;
+    let index = (scaled / length).min(segments - 1);
+    let remainder = scaled - index * length;
+    let (from, to) = (&stop(index), &stop(index + 1));
+    let channel = |from: u8, to: u8| {
+        let from = from as usize;
+        let to = to as usize;
+        ((from * (length - remainder) + to * remainder + length / 2) / length) as u8
+    };
+    Some(RgbColor {
+        red: channel(from.red, to.red),
+        green: channel(from.green, to.green),
+        blue: channel(from.blue, to.blue),
+    })
+}
+
+/// How the lines of the box are colored.
+pub enum BorderPaint {
+    Solid(Color),
+    Gradient(Gradient),
+}
+
+impl BorderPaint {
+    /// The single color of the border, if it isn't a gradient.
+    pub fn solid(&self) -> Option<Color> {
+        match self {
+            BorderPaint::Solid(color) => Some(*color),
+            BorderPaint::Gradient(_) => None,
+        }
+    }
+
+    /// Color border glyphs that start at column `x` and row `y` of a box of the given
+    /// `(width, height)`. Neighbouring glyphs that end up the same color share one
+    /// escape sequence.
+    pub fn paint(
+        &self,
+        glyphs: &str,
+        x: usize,
+        y: usize,
+        size: (usize, usize),
+        level: ColorLevel,
+    ) -> String {
+        let mut painted = String::new();
+        // writing to a String can't fail
+        let _ = self.paint_to(&mut painted, [glyphs], x, y, size, level);
+        painted
+    }
+
+    /// Write the border glyphs of `pieces`, drawn one after another from column `x`
+    /// of row `y`, colored the same way as [paint](#method.paint).
+    pub fn paint_to<'a, W, I>(
+        &self,
+        out: &mut W,
+        pieces: I,
+        x: usize,
+        y: usize,
+        size: (usize, usize),
+        level: ColorLevel,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut run_color = None;
+        let mut column = x;
+        for glyph in pieces.into_iter().flat_map(|piece| piece.graphemes(true)) {
+            let color = match self {
+                BorderPaint::Solid(color) => *color,
+                BorderPaint::Gradient(gradient) => gradient
+                    .color_at(column, y, size.0, size.1)
+                    .map_or(Color::Default, Color::Rgb),
+            }
+            .downgrade(level);
+            if run_color != Some(color) {
+                if run_color.is_some_and(|color| color != Color::Default) {
+                    out.write_str(RESET_CODE)?;
+                }
+                color.write_foreground(out)?;
+                run_color = Some(color);
+            }
+            out.write_str(glyph)?;
+            column += width::display_width(glyph);
+        }
+        if run_color.is_some_and(|color| color != Color::Default) {
+            out.write_str(RESET_CODE)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: RgbColor = RgbColor {
+        red: 255,
+        green: 0,
+        blue: 0,
+    };
+    const BLUE: RgbColor = RgbColor {
+        red: 0,
+        green: 0,
+        blue: 255,
+    };
+
+    #[test]
+    fn test_interpolate() {
+        let purple = RgbColor {
+            red: 128,
+            green: 0,
+            blue: 128,
+        };
+        assert_eq!(interpolate(&[RED, BLUE], 0, 10), Some(RED));
+        assert_eq!(interpolate(&[RED, BLUE], 5, 10), Some(purple));
+        assert_eq!(interpolate(&[RED, BLUE], 10, 10), Some(BLUE));
+        assert_eq!(interpolate(&[RED, BLUE, RED], 10, 10), Some(RED));
+        assert_eq!(interpolate(&[RED, BLUE, RED], 5, 10), Some(BLUE));
+    }
+
+    #[test]
+    fn test_interpolate_few_stops() {
+        assert_eq!(interpolate(&[RED], 3, 10), Some(RED));
+        assert_eq!(interpolate(&[], 3, 10), None);
+        assert_eq!(interpolate(&[RED, BLUE], 0, 0), Some(RED));
+    }
+
+    #[test]
+    fn test_horizontal_and_vertical() {
+        let gradient = Gradient::horizontal(vec![RED, BLUE]);
+        assert_eq!(gradient.color_at(0, 3, 11, 5), Some(RED));
+        assert_eq!(gradient.color_at(10, 0, 11, 5), Some(BLUE));
+
+        let gradient = Gradient::vertical(vec![RED, BLUE]);
+        assert_eq!(gradient.color_at(10, 0, 11, 5), Some(RED));
+        assert_eq!(gradient.color_at(0, 4, 11, 5), Some(BLUE));
+    }
+
+    #[test]
+    fn test_perimeter() {
+        let gradient = Gradient::perimeter(vec![RED, BLUE]);
+        // halfway around a 5 x 3 box is the bottom right corner
+        assert_eq!(gradient.color_at(0, 0, 5, 3), Some(RED));
+        assert_eq!(gradient.color_at(4, 2, 5, 3), Some(BLUE));
+        assert_eq!(gradient.color_at(0, 1, 5, 3), gradient.color_at(1, 0, 5, 3));
+    }
+
+    #[test]
+    fn test_paint_gradient() {
+        let paint = BorderPaint::Gradient(Gradient::horizontal(vec![RED, BLUE]));
+        let expected = "\x1B[38;2;255;0;0m┌\x1B[0m\
+                        \x1B[38;2;128;0;128m─\x1B[0m\
+                        \x1B[38;2;0;0;255m┐\x1B[0m";
+        assert_eq!(
+            paint.paint("┌─┐", 0, 0, (3, 3), ColorLevel::TrueColor),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_paint_merges_downgraded_runs() {
+        let paint = BorderPaint::Gradient(Gradient::horizontal(vec![RED, RED]));
+        let expected = "\x1B[91m┌─┐\x1B[0m";
+        assert_eq!(
+            paint.paint("┌─┐", 0, 0, (3, 3), ColorLevel::Ansi16),
+            expected
+        );
+        assert_eq!(paint.paint("┌─┐", 0, 0, (3, 3), ColorLevel::None), "┌─┐");
+    }
+
+    #[test]
+    fn test_paint_to_pieces() {
+        let paint = BorderPaint::Gradient(Gradient::vertical(vec![RED, BLUE]));
+        let mut painted = String::new();
+        paint
+            .paint_to(
+                &mut painted,
+                ["┌", "─", "─", "┐"],
+                0,
+                0,
+                (4, 3),
+                ColorLevel::TrueColor,
+            )
+            .unwrap();
+        assert_eq!(painted, "\x1B[38;2;255;0;0m┌──┐\x1B[0m");
+    }
+}
@@ -1,92 +1,254 @@
 pub mod ansi_color_codes;
+pub mod gradient;
 pub mod rgb_color;
+pub mod support;
+pub mod text_style;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
 use ansi_color_codes::AnsiColorCode;
 use rgb_color::RgbColor;
 
-const RESET_CODE: &'static str = "\x1B[0m";
+pub const RESET_CODE: &str = "\x1B[0m";
+
+/// A color for the border, title, text or background of the box.
+///
+/// Anything that converts into a color can be passed where a color is accepted,
+/// and colors can be parsed from strings:
+/// ```
+/// use bauxite::{AnsiColorCode, Color, RgbColor};
+///
+/// assert_eq!("red".parse(), Ok(Color::Ansi(AnsiColorCode::Red)));
+/// assert_eq!("bright-blue".parse(), Ok(Color::Ansi(AnsiColorCode::BrightBlue)));
+/// assert_eq!("214".parse(), Ok(Color::Indexed(214)));
+/// assert_eq!("#ff8800".parse(), Ok(Color::Rgb(RgbColor { red: 255, green: 136, blue: 0 })));
+/// assert_eq!(Color::from((255, 136, 0)), "#f80".parse().unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Color {
+    /// Simplest ANSI color codes defined by the AnsiColorCode enumerated type.
+    Ansi(AnsiColorCode),
 
-/// Stores the line color information for the box.
-/// Only one line color type should have a value at a time.
-pub struct LineColor {
-    pub ansi: Option<AnsiColorCode>,
-    pub rgb: Option<RgbColor>,
-    pub color8: Option<u8>,
+    /// 8 bit color code.
+    ///
+    /// 0-7 are standard colors
+    /// 8-15 are high intensity colors
+    /// 16-231 are defined by 16 + 36 x r + 6 x g + b (0 <= r, g, b <= 5)
+    /// 232-255 are grayscale from black to white in 24 steps
+    Indexed(u8),
+
+    /// 24 bit RGB color.
+    Rgb(RgbColor),
+
+    /// The terminal's own color, no escape codes are written.
+    #[default]
+    Default,
 }
 
-impl LineColor {
-    /// Constructs a line color struct with no values for each color.
-    pub fn new() -> LineColor {
-        LineColor {
-            ansi: None,
-            rgb: None,
-            color8: None,
+impl Color {
+    /// Wraps the given text in the color as the foreground color.
+    pub fn wrap_color(&self, text: String) -> String {
+        match self.foreground_code() {
+            Some(code) => format!("{}{}{}", code, text, RESET_CODE),
+            None => text,
         }
     }
 
-    /// Wraps the given text in the color specified by the LineColor struct.
-    pub fn wrap_color(&self, text: String) -> String {
-        if let Some(ansi) = &self.ansi {
-            self.color_code(text, &ansi)
-        } else if let Some(rgb) = &self.rgb {
-            self.color_rgb(text, rgb)
-        } else if let Some(color8) = &self.color8 {
-            self.color_8(text, color8)
-        } else {
-            text
+    /// Escape sequence that sets this color as the foreground color, if there is one.
+    pub fn foreground_code(&self) -> Option<String> {
+        self.code(false)
+    }
+
+    /// Escape sequence that sets this color as the background color, if there is one.
+    pub fn background_code(&self) -> Option<String> {
+        self.code(true)
+    }
+
+    /// Write the escape sequence that sets this color as the foreground color, if
+    /// there is one.
+    pub fn write_foreground<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        self.write_code(out, false)
+    }
+
+    fn code(&self, background: bool) -> Option<String> {
+        if *self == Color::Default {
+            return None;
         }
+        let mut code = String::new();
+        // writing to a String can't fail
+        let _ = self.write_code(&mut code, background);
+        Some(code)
     }
 
-    /// Sets 8 bit color code.
-    /// 0-7 are standard colors
-    /// 8-15 are high intensity colors
-    /// 16-231 are defined by 16 + 36 x r + 6 x g + b (0 <= r, g, b <= 5)
-    /// 232-255 are grayscale from black to white in 24 steps
-    fn color_8(&self, text: String, color: &u8) -> String {
-        format!("\x1B[38;5;{}m{}{}", color, text, RESET_CODE)
+    fn write_code<W: fmt::Write>(&self, out: &mut W, background: bool) -> fmt::Result {
+        match self {
+            Color::Ansi(ansi) => color_code(out, ansi, background),
+            Color::Indexed(color8) => color_8(out, *color8, background),
+            Color::Rgb(rgb) => color_rgb(out, rgb, background),
+            Color::Default => Ok(()),
+        }
     }
+}
 
-    /// Basic RGB colors.
-    fn color_rgb(&self, text: String, rgb: &RgbColor) -> String {
-        format!(
-            "\x1B[38;2;{};{};{}m{}{}",
-            rgb.red, rgb.green, rgb.blue, text, RESET_CODE
-        )
-    }
-
-    /// Simplest ANSI color codes defind by AnsiColorCode enumerated type.
-    fn color_code(&self, text: String, color_code: &AnsiColorCode) -> String {
-        let color = match color_code {
-            AnsiColorCode::Black => "30",
-            AnsiColorCode::Red => "31",
-            AnsiColorCode::Green => "32",
-            AnsiColorCode::Yellow => "33",
-            AnsiColorCode::Blue => "34",
-            AnsiColorCode::Magenta => "35",
-            AnsiColorCode::Cyan => "36",
-            AnsiColorCode::White => "37",
-            AnsiColorCode::BrightBlack => "90",
-            AnsiColorCode::BrightRed => "91",
-            AnsiColorCode::BrightGreen => "92",
-            AnsiColorCode::BrightYellow => "93",
-            AnsiColorCode::BrightBlue => "94",
-            AnsiColorCode::BrightMagenta => "95",
-            AnsiColorCode::BrightCyan => "96",
-            AnsiColorCode::BrightWhite => "97",
+impl From<AnsiColorCode> for Color {
+    fn from(code: AnsiColorCode) -> Color {
+        Color::Ansi(code)
+    }
+}
+
+impl From<u8> for Color {
+    fn from(color: u8) -> Color {
+        Color::Indexed(color)
+    }
+}
+
+impl From<RgbColor> for Color {
+    fn from(rgb: RgbColor) -> Color {
+        Color::Rgb(rgb)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Color {
+        Color::Rgb(RgbColor { red, green, blue })
+    }
+}
+
+/// Error returned when a string doesn't name a color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "invalid color: {:?}", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses color names like `red` or `bright-blue`, 8 bit color codes like `214`,
+/// hex RGB colors like `#ff8800` or `#f80` and `default`. Names ignore case and
+/// may use `-`, `_` or nothing between words.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<Color, ParseColorError> {
+        let error = || ParseColorError {
+            input: String::from(input),
         };
-        format!("\x1B[{}m{}{}", color, text, RESET_CODE)
+        let input = input.trim();
+
+        if let Some(hex) = input.strip_prefix('#') {
+            return parse_hex(hex).map(Color::Rgb).ok_or_else(error);
+        }
+        if input.chars().all(|c| c.is_ascii_digit()) {
+            return input.parse().map(Color::Indexed).map_err(|_| error());
+        }
+
+        // separators are only dropped between the words of a name
+        let name = input.to_ascii_lowercase().replace(['-', '_'], "");
+        let code = match name.as_str() {
+            "default" => return Ok(Color::Default),
+            "black" => AnsiColorCode::Black,
+            "red" => AnsiColorCode::Red,
+            "green" => AnsiColorCode::Green,
+            "yellow" => AnsiColorCode::Yellow,
+            "blue" => AnsiColorCode::Blue,
+            "magenta" => AnsiColorCode::Magenta,
+            "cyan" => AnsiColorCode::Cyan,
+            "white" => AnsiColorCode::White,
+            "brightblack" => AnsiColorCode::BrightBlack,
+            "brightred" => AnsiColorCode::BrightRed,
+            "brightgreen" => AnsiColorCode::BrightGreen,
+            "brightyellow" => AnsiColorCode::BrightYellow,
+            "brightblue" => AnsiColorCode::BrightBlue,
+            "brightmagenta" => AnsiColorCode::BrightMagenta,
+            "brightcyan" => AnsiColorCode::BrightCyan,
+            "brightwhite" => AnsiColorCode::BrightWhite,
+            _ => return Err(error()),
+        };
+        Ok(Color::Ansi(code))
     }
 }
 
+/// Parse the digits of a `#rrggbb` or `#rgb` color.
+fn parse_hex(hex: &str) -> Option<RgbColor> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        6 => Some(RgbColor {
+            red: channel(&hex[0..2])?,
+            green: channel(&hex[2..4])?,
+            blue: channel(&hex[4..6])?,
+        }),
+        3 => Some(RgbColor {
+            red: channel(&hex[0..1])? * 17,
+            green: channel(&hex[1..2])? * 17,
+            blue: channel(&hex[2..3])? * 17,
+        }),
+        _ => None,
+    }
+}
+
+/// Sets 8 bit color code.
+fn color_8<W: fmt::Write>(out: &mut W, color: u8, background: bool) -> fmt::Result {
+    let layer = if background { 48 } else { 38 };
+    write!(out, "\x1B[{};5;{}m", layer, color)
+}
+
+/// Basic RGB colors.
+fn color_rgb<W: fmt::Write>(out: &mut W, rgb: &RgbColor, background: bool) -> fmt::Result {
+    let layer = if background { 48 } else { 38 };
+    write!(
+        out,
+        "\x1B[{};2;{};{};{}m",
+        layer, rgb.red, rgb.green, rgb.blue
+    )
+}
+
+/// Simplest ANSI color codes defind by AnsiColorCode enumerated type.
+/// Background codes are the foreground codes offset by 10.
+fn color_code<W: fmt::Write>(
+    out: &mut W,
+    color_code: &AnsiColorCode,
+    background: bool,
+) -> fmt::Result {
+    let color = match color_code {
+        AnsiColorCode::Black => 30,
+        AnsiColorCode::Red => 31,
+        AnsiColorCode::Green => 32,
+        AnsiColorCode::Yellow => 33,
+        AnsiColorCode::Blue => 34,
+        AnsiColorCode::Magenta => 35,
+        AnsiColorCode::Cyan => 36,
+        AnsiColorCode::White => 37,
+        AnsiColorCode::BrightBlack => 90,
+        AnsiColorCode::BrightRed => 91,
+        AnsiColorCode::BrightGreen => 92,
+        AnsiColorCode::BrightYellow => 93,
+        AnsiColorCode::BrightBlue => 94,
+        AnsiColorCode::BrightMagenta => 95,
+        AnsiColorCode::BrightCyan => 96,
+        AnsiColorCode::BrightWhite => 97,
+    };
+    let offset = if background { 10 } else { 0 };
+    write!(out, "\x1B[{}m", color + offset)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_color_8() {
-        let mut color = LineColor::new();
         let color_code = 9;
-        color.color8 = Some(color_code);
+        let color = Color::Indexed(color_code);
 
         let message = "Arbitrary Text";
 
@@ -97,9 +259,7 @@ mod tests {
 
     #[test]
     fn test_color_code() {
-        let mut color = LineColor::new();
-        let color_code = AnsiColorCode::BrightBlack;
-        color.ansi = Some(color_code);
+        let color = Color::Ansi(AnsiColorCode::BrightBlack);
         let message = "Arbitrary text";
         let wrapped_message = color.wrap_color(String::from(message));
         let expected_message = format!("\x1B[{}m{}{}", "90", message, RESET_CODE);
@@ -108,13 +268,12 @@ mod tests {
 
     #[test]
     fn test_color_rgb() {
-        let mut color = LineColor::new();
         let rgb = RgbColor {
             red: 100,
             green: 101,
             blue: 102,
         };
-        color.rgb = Some(rgb);
+        let color = Color::Rgb(rgb);
 
         let message = "Arbitrary text";
         let wrapped_message = color.wrap_color(String::from(message));
@@ -124,4 +283,75 @@ mod tests {
         );
         assert_eq!(wrapped_message, expected_message);
     }
+
+    #[test]
+    fn test_default_color() {
+        let message = "Arbitrary text";
+        let wrapped_message = Color::Default.wrap_color(String::from(message));
+        assert_eq!(wrapped_message, message);
+        assert_eq!(Color::Default.background_code(), None);
+    }
+
+    #[test]
+    fn test_background_codes() {
+        let color = Color::Ansi(AnsiColorCode::BrightBlue);
+        assert_eq!(color.background_code(), Some(String::from("\x1B[104m")));
+
+        let color = Color::Indexed(214);
+        assert_eq!(
+            color.background_code(),
+            Some(String::from("\x1B[48;5;214m"))
+        );
+
+        let color = Color::from((1, 2, 3));
+        assert_eq!(
+            color.background_code(),
+            Some(String::from("\x1B[48;2;1;2;3m"))
+        );
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!("red".parse(), Ok(Color::Ansi(AnsiColorCode::Red)));
+        assert_eq!(
+            "Bright-Blue".parse(),
+            Ok(Color::Ansi(AnsiColorCode::BrightBlue))
+        );
+        assert_eq!(
+            "bright_white".parse(),
+            Ok(Color::Ansi(AnsiColorCode::BrightWhite))
+        );
+        assert_eq!(
+            "brightblack".parse(),
+            Ok(Color::Ansi(AnsiColorCode::BrightBlack))
+        );
+        assert_eq!("default".parse(), Ok(Color::Default));
+    }
+
+    #[test]
+    fn test_parse_indexed() {
+        assert_eq!("214".parse(), Ok(Color::Indexed(214)));
+        assert_eq!("0".parse(), Ok(Color::Indexed(0)));
+        assert!("256".parse::<Color>().is_err());
+        assert!("--5".parse::<Color>().is_err());
+        assert!("1_0".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!("#ff8800".parse(), Ok(Color::from((255, 136, 0))));
+        assert_eq!("#FF8800".parse(), Ok(Color::from((255, 136, 0))));
+        assert_eq!("#f80".parse(), Ok(Color::from((255, 136, 0))));
+        assert!("#ff88".parse::<Color>().is_err());
+        assert!("#gg8800".parse::<Color>().is_err());
+        assert!("#f-f-8-8-0-0".parse::<Color>().is_err());
+        assert!("#ff_88_00".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = "purple".parse::<Color>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid color: \"purple\"");
+        assert!("".parse::<Color>().is_err());
+    }
 }
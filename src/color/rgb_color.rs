@@ -1,4 +1,5 @@
 /// Defines RGB color for the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RgbColor {
     pub red: u8,
     pub green: u8,
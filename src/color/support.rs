@@ -0,0 +1,300 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use super::ansi_color_codes::AnsiColorCode;
+use super::rgb_color::RgbColor;
+use super::Color;
+
+/// How many colors the terminal can display, from fewest to most.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorLevel {
+    /// No colors or text attributes at all.
+    None,
+
+    /// The 16 colors of [AnsiColorCode](enum.AnsiColorCode.html).
+    Ansi16,
+
+    /// The 256 colors of the 8 bit palette.
+    Ansi256,
+
+    /// Any 24 bit RGB color.
+    TrueColor,
+}
+
+/// Decides which colors are written when the box is rendered.
+///
+/// Colors the terminal can't display are replaced with the nearest color it can,
+/// RGB colors become the closest 8 bit or AnsiColorCode color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// Detect support from the environment and whether stdout is a terminal.
+    ///
+    /// `NO_COLOR` turns colors off and `CLICOLOR_FORCE` turns them on even when
+    /// stdout isn't a terminal. The level is read from `COLORTERM` and `TERM`.
+    /// Only stdout is checked, even when the box is written to stderr or a file.
+    Auto,
+
+    /// Always write colors exactly as they were given. This is the default.
+    Always,
+
+    /// Never write colors.
+    Never,
+
+    /// Write colors downgraded to the given level.
+    Level(ColorLevel),
+}
+
+impl ColorSupport {
+    /// The color level to render with under this policy.
+    pub fn level(&self) -> ColorLevel {
+        match self {
+            ColorSupport::Auto => {
+                detect_level(|name| env::var(name).ok(), io::stdout().is_terminal())
+            }
+            ColorSupport::Always => ColorLevel::TrueColor,
+            ColorSupport::Never => ColorLevel::None,
+            ColorSupport::Level(level) => *level,
+        }
+    }
+}
+
+/// Work out the color level from environment variables and whether the output
+/// is a terminal.
+fn detect_level<F: Fn(&str) -> Option<String>>(var: F, is_terminal: bool) -> ColorLevel {
+    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return ColorLevel::None;
+    }
+
+    let forced = var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0");
+    let term = var("TERM").unwrap_or_default();
+    if !forced && (!is_terminal || term == "dumb") {
+        return ColorLevel::None;
+    }
+
+    let colorterm = var("COLORTERM").unwrap_or_default();
+    if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+        ColorLevel::TrueColor
+    } else if term.contains("256color") {
+        ColorLevel::Ansi256
+    } else {
+        ColorLevel::Ansi16
+    }
+}
+
+/// The 16 ANSI colors with the RGB values xterm uses for them.
+const ANSI_PALETTE: [(AnsiColorCode, RgbColor); 16] = [
+    (AnsiColorCode::Black, rgb(0, 0, 0)),
+    (AnsiColorCode::Red, rgb(205, 0, 0)),
+    (AnsiColorCode::Green, rgb(0, 205, 0)),
+    (AnsiColorCode::Yellow, rgb(205, 205, 0)),
+    (AnsiColorCode::Blue, rgb(0, 0, 238)),
+    (AnsiColorCode::Magenta, rgb(205, 0, 205)),
+    (AnsiColorCode::Cyan, rgb(0, 205, 205)),
+    (AnsiColorCode::White, rgb(229, 229, 229)),
+    (AnsiColorCode::BrightBlack, rgb(127, 127, 127)),
+    (AnsiColorCode::BrightRed, rgb(255, 0, 0)),
+    (AnsiColorCode::BrightGreen, rgb(0, 255, 0)),
+    (AnsiColorCode::BrightYellow, rgb(255, 255, 0)),
+    (AnsiColorCode::BrightBlue, rgb(92, 92, 255)),
+    (AnsiColorCode::BrightMagenta, rgb(255, 0, 255)),
+    (AnsiColorCode::BrightCyan, rgb(0, 255, 255)),
+    (AnsiColorCode::BrightWhite, rgb(255, 255, 255)),
+];
+
+/// Channel values of the 6 x 6 x 6 color cube in the 8 bit palette.
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+const fn rgb(red: u8, green: u8, blue: u8) -> RgbColor {
+    RgbColor { red, green, blue }
+}
+
+impl Color {
+    /// The closest color that can be displayed at the given level.
+    pub fn downgrade(&self, level: ColorLevel) -> Color {
+        match (level, *self) {
+            (ColorLevel::None, _) | (_, Color::Default) => Color::Default,
+            (ColorLevel::TrueColor, color) => color,
+            (ColorLevel::Ansi256, Color::Rgb(color)) => Color::Indexed(nearest_indexed(&color)),
+            (ColorLevel::Ansi256, color) => color,
+            (ColorLevel::Ansi16, Color::Rgb(color)) => Color::Ansi(nearest_ansi(&color)),
+            (ColorLevel::Ansi16, Color::Indexed(index)) if index < 16 => {
+                Color::Ansi(ANSI_PALETTE[index as usize].0)
+            }
+            (ColorLevel::Ansi16, Color::Indexed(index)) => {
+                Color::Ansi(nearest_ansi(&indexed_to_rgb(index)))
+            }
+            (ColorLevel::Ansi16, color) => color,
+        }
+    }
+}
+
+/// Squared distance between two colors.
+fn distance(a: &RgbColor, b: &RgbColor) -> u32 {
+    let channel = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    channel(a.red, b.red) + channel(a.green, b.green) + channel(a.blue, b.blue)
+}
+
+/// RGB value of an 8 bit color code.
+fn indexed_to_rgb(index: u8) -> RgbColor {
+    match index {
+        0..=15 => ANSI_PALETTE[index as usize].1,
+        16..=231 => {
+            let cube = index - 16;
+            rgb(
+                CUBE_STEPS[(cube / 36) as usize],
+                CUBE_STEPS[(cube / 6 % 6) as usize],
+                CUBE_STEPS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let gray = 8 + 10 * (index - 232);
+            rgb(gray, gray, gray)
+        }
+    }
+}
+
+/// Closest color in the color cube or grayscale ramp of the 8 bit palette.
+fn nearest_indexed(color: &RgbColor) -> u8 {
+    let nearest_step = |channel: u8| {
+        (0..CUBE_STEPS.len())
+            .min_by_key(|step| (i32::from(CUBE_STEPS[*step]) - i32::from(channel)).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16
+        + 36 * nearest_step(color.red)
+        + 6 * nearest_step(color.green)
+        + nearest_step(color.blue);
+
+    let average = (u16::from(color.red) + u16::from(color.green) + u16::from(color.blue)) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if distance(color, &indexed_to_rgb(gray)) < distance(color, &indexed_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Closest of the 16 AnsiColorCode colors.
+fn nearest_ansi(color: &RgbColor) -> AnsiColorCode {
+    ANSI_PALETTE
+        .iter()
+        .min_by_key(|(_, palette)| distance(color, palette))
+        .map(|(code, _)| *code)
+        .unwrap_or(AnsiColorCode::White)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| String::from(*value))
+        }
+    }
+
+    #[test]
+    fn test_detect_terminal() {
+        assert_eq!(
+            detect_level(env(&[("TERM", "xterm")]), true),
+            ColorLevel::Ansi16
+        );
+        assert_eq!(
+            detect_level(env(&[("TERM", "xterm-256color")]), true),
+            ColorLevel::Ansi256
+        );
+        assert_eq!(
+            detect_level(
+                env(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+                true
+            ),
+            ColorLevel::TrueColor
+        );
+        assert_eq!(
+            detect_level(env(&[("TERM", "dumb")]), true),
+            ColorLevel::None
+        );
+    }
+
+    #[test]
+    fn test_detect_not_terminal() {
+        assert_eq!(
+            detect_level(env(&[("TERM", "xterm")]), false),
+            ColorLevel::None
+        );
+        assert_eq!(
+            detect_level(env(&[("TERM", "xterm"), ("CLICOLOR_FORCE", "1")]), false),
+            ColorLevel::Ansi16
+        );
+        assert_eq!(
+            detect_level(env(&[("TERM", "xterm"), ("CLICOLOR_FORCE", "0")]), false),
+            ColorLevel::None
+        );
+    }
+
+    #[test]
+    fn test_detect_no_color() {
+        let vars = [
+            ("TERM", "xterm"),
+            ("NO_COLOR", "1"),
+            ("CLICOLOR_FORCE", "1"),
+        ];
+        assert_eq!(detect_level(env(&vars), true), ColorLevel::None);
+        let vars = [("TERM", "xterm"), ("NO_COLOR", "")];
+        assert_eq!(detect_level(env(&vars), true), ColorLevel::Ansi16);
+    }
+
+    #[test]
+    fn test_fixed_policies() {
+        assert_eq!(ColorSupport::Always.level(), ColorLevel::TrueColor);
+        assert_eq!(ColorSupport::Never.level(), ColorLevel::None);
+        assert_eq!(
+            ColorSupport::Level(ColorLevel::Ansi256).level(),
+            ColorLevel::Ansi256
+        );
+    }
+
+    #[test]
+    fn test_downgrade_to_256() {
+        let orange = Color::from((255, 136, 0));
+        assert_eq!(orange.downgrade(ColorLevel::Ansi256), Color::Indexed(208));
+        let gray = Color::from((100, 100, 100));
+        assert_eq!(gray.downgrade(ColorLevel::Ansi256), Color::Indexed(241));
+        let red = Color::Ansi(AnsiColorCode::Red);
+        assert_eq!(red.downgrade(ColorLevel::Ansi256), red);
+    }
+
+    #[test]
+    fn test_downgrade_to_16() {
+        let orange = Color::from((250, 10, 20));
+        assert_eq!(
+            orange.downgrade(ColorLevel::Ansi16),
+            Color::Ansi(AnsiColorCode::BrightRed)
+        );
+        assert_eq!(
+            Color::Indexed(4).downgrade(ColorLevel::Ansi16),
+            Color::Ansi(AnsiColorCode::Blue)
+        );
+        assert_eq!(
+            Color::Indexed(46).downgrade(ColorLevel::Ansi16),
+            Color::Ansi(AnsiColorCode::BrightGreen)
+        );
+    }
+
+    #[test]
+    fn test_downgrade_to_none() {
+        let orange = Color::from((255, 136, 0));
+        assert_eq!(orange.downgrade(ColorLevel::None), Color::Default);
+        assert_eq!(orange.downgrade(ColorLevel::TrueColor), orange);
+    }
+
+    #[test]
+    fn test_indexed_round_trip() {
+        for index in 16..=255 {
+            assert_eq!(nearest_indexed(&indexed_to_rgb(index)), index);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use super::support::ColorLevel;
+use super::{Color, RESET_CODE};
+use crate::ansi;
+
+/// Colors and attributes for the text inside the box.
+///
+/// The style only applies to the content, the border keeps its own color.
+/// ```
+/// use bauxite::{AnsiColorCode, BoxBuilder, TextStyle};
+///
+/// let style = TextStyle::new().foreground(AnsiColorCode::Yellow).bold();
+/// println!("{}", BoxBuilder::from("Warning").text_style(style));
+/// ```
+pub struct TextStyle {
+    foreground: Color,
+    background: Color,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl TextStyle {
+    /// Constructs a style that leaves the text unchanged.
+    pub fn new() -> TextStyle {
+        TextStyle {
+            foreground: Color::Default,
+            background: Color::Default,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    /// Sets the text color using [Color](enum.Color.html).
+    pub fn foreground<C: Into<Color>>(mut self, color: C) -> Self {
+        self.foreground = color.into();
+        self
+    }
+
+    /// Sets the color behind the text using [Color](enum.Color.html).
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = color.into();
+        self
+    }
+
+    /// Draw the text in bold.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Draw the text faint.
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Draw the text in italics.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underline the text.
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Escape sequences that turn this style on, empty if the style changes nothing.
+    /// Colors are downgraded to the given level and nothing is written at `None`.
+    pub fn codes(&self, level: ColorLevel) -> String {
+        let mut codes = String::new();
+        if level == ColorLevel::None {
+            return codes;
+        }
+        let attributes = [
+            (self.bold, "\x1B[1m"),
+            (self.dim, "\x1B[2m"),
+            (self.italic, "\x1B[3m"),
+            (self.underline, "\x1B[4m"),
+        ];
+        for (enabled, code) in attributes.iter() {
+            if *enabled {
+                codes += code;
+            }
+        }
+        codes += &self
+            .foreground
+            .downgrade(level)
+            .foreground_code()
+            .unwrap_or_default();
+        codes += &self
+            .background
+            .downgrade(level)
+            .background_code()
+            .unwrap_or_default();
+        codes
+    }
+
+    /// Wraps the given text in the style. Resets inside the text are followed by the
+    /// style again so it covers the whole text.
+    pub fn wrap_style(&self, text: &str, level: ColorLevel) -> String {
+        let codes = self.codes(level);
+        if codes.is_empty() || text.is_empty() {
+            String::from(text)
+        } else {
+            format!("{}{}", ansi::restyle(text, &codes), RESET_CODE)
+        }
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> TextStyle {
+        TextStyle::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ansi_color_codes::AnsiColorCode;
+    use crate::color::rgb_color::RgbColor;
+
+    #[test]
+    fn test_empty_style() {
+        assert_eq!(
+            TextStyle::new().wrap_style("text", ColorLevel::TrueColor),
+            "text"
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let style = TextStyle::new().bold().dim().italic().underline();
+        let expected = format!("\x1B[1m\x1B[2m\x1B[3m\x1B[4mtext{}", RESET_CODE);
+        assert_eq!(style.wrap_style("text", ColorLevel::TrueColor), expected);
+    }
+
+    #[test]
+    fn test_colors() {
+        let style = TextStyle::new()
+            .foreground(214)
+            .background(AnsiColorCode::Blue);
+        let expected = format!("\x1B[38;5;214m\x1B[44mtext{}", RESET_CODE);
+        assert_eq!(style.wrap_style("text", ColorLevel::TrueColor), expected);
+
+        let style = TextStyle::new()
+            .foreground(AnsiColorCode::Red)
+            .foreground((1, 2, 3))
+            .background(RgbColor {
+                red: 4,
+                green: 5,
+                blue: 6,
+            });
+        let expected = format!("\x1B[38;2;1;2;3m\x1B[48;2;4;5;6mtext{}", RESET_CODE);
+        assert_eq!(style.wrap_style("text", ColorLevel::TrueColor), expected);
+    }
+
+    #[test]
+    fn test_downgraded_style() {
+        let style = TextStyle::new().bold().foreground((255, 0, 0));
+        let expected = format!("\x1B[1m\x1B[91mtext{}", RESET_CODE);
+        assert_eq!(style.wrap_style("text", ColorLevel::Ansi16), expected);
+        assert_eq!(style.wrap_style("text", ColorLevel::None), "text");
+    }
+
+    #[test]
+    fn test_style_survives_resets() {
+        let style = TextStyle::new().bold();
+        let expected = format!("\x1B[1ma\x1B[0m\x1B[1mb{}", RESET_CODE);
+        assert_eq!(
+            style.wrap_style("a\x1B[0mb", ColorLevel::TrueColor),
+            expected
+        );
+    }
+}
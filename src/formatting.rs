@@ -1,29 +1,253 @@
-/// Sets text alignment inside the line box to the left or right.
+/// Sets text alignment inside the line box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Alignment {
     Left,
     Right,
+
+    /// Center each line. When the leftover space can't be split evenly the extra
+    /// column goes on the right.
+    Center,
+
+    /// Stretch the spaces between words so every wrapped line fills the width of the
+    /// box. The last line of each paragraph is left aligned.
+    Justify,
+}
+
+/// Sets where a title or footer is placed along the border of the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+    Left,
+
+    /// Center the text in the border. When the leftover space can't be split evenly
+    /// the extra column goes on the right.
+    Center,
+    Right,
+}
+
+/// Sets where shorter boxes are placed next to taller ones in a [Row](struct.Row.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalAlignment {
+    Top,
+
+    /// Center the box. When the leftover rows can't be split evenly the extra row
+    /// goes below.
+    Middle,
+    Bottom,
+}
+
+/// Sets how lines longer than the width of the box are broken onto new lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Break lines at exactly the width of the box, even in the middle of a word.
+    Char,
+
+    /// Break lines at whitespace. Words longer than the width of the box are split
+    /// as in `Char` mode.
+    Word,
+
+    /// Break lines at whitespace. Words longer than the width of the box are split
+    /// with a trailing hyphen.
+    WordWithHyphenation,
+
+    /// Never break lines, anything past the width of the box is cut off. The same as
+    /// [Overflow::Clip](enum.Overflow.html#variant.Clip).
+    NoWrap,
+}
+
+/// Sets what happens to lines longer than the width of the box.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Break long lines onto new lines using the [WrapMode](enum.WrapMode.html) of the
+    /// box.
+    #[default]
+    Wrap,
+
+    /// Keep each line on one row, cutting long lines short and marking the cut with
+    /// the ellipsis.
+    Truncate {
+        ellipsis: String,
+        position: TruncatePosition,
+    },
+
+    /// Keep each line on one row, anything past the width of the box is cut off.
+    Clip,
+}
+
+impl Overflow {
+    /// Truncate long lines with `…` at the end.
+    pub fn truncate() -> Overflow {
+        Overflow::Truncate {
+            ellipsis: String::from("…"),
+            position: TruncatePosition::End,
+        }
+    }
+}
+
+/// Sets which part of a truncated line is cut out and replaced with the ellipsis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncatePosition {
+    /// Keep the start of the line.
+    End,
+
+    /// Keep the start and end of the line. When the kept columns can't be split
+    /// evenly the extra column goes to the start.
+    Middle,
+
+    /// Keep the end of the line.
+    Start,
+}
+
+/// Sets how wide a box may grow before its lines wrap.
+///
+/// Widths are counted in columns and take in the border and padding of the box. The
+/// widths measured against the terminal also take in the margin and shadow, so the
+/// whole box fits on screen. A box nested in another box measures the terminal and
+/// percentages against the inside of the box around it instead of the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Width {
+    /// Wrap at a fixed number of columns, 80 unless the box sets its own. The margin
+    /// and shadow aren't counted.
+    Fixed(usize),
+
+    /// Wrap at the width of the terminal, found by asking the terminal for its size
+    /// then from the `COLUMNS` environment variable, or 80 columns when neither works.
+    /// Output then depends on where the program runs.
+    Terminal,
+
+    /// Wrap at a percentage of the width of the terminal.
+    Percent(usize),
+
+    /// Stretch the box to the full width of the terminal even when the content is
+    /// shorter.
+    Fill,
 }
 
+impl From<usize> for Width {
+    fn from(width: usize) -> Width {
+        Width::Fixed(width)
+    }
+}
+
+/// Space between the border of the box and its content on each side.
+///
+/// Horizontal padding is counted in columns and vertical padding in rows. Like CSS
+/// the shorthand tuple forms list the sides clockwise starting from the top:
+/// ```
+/// use bauxite::Padding;
+///
+/// assert_eq!(Padding::from((1, 2)), Padding::symmetric(1, 2));
+/// assert_eq!(Padding::from((1, 2, 3)), Padding::new(1, 2, 3, 2));
+/// assert_eq!(Padding::from((1, 2, 3, 4)), Padding::new(1, 2, 3, 4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Padding {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Padding {
+    /// Padding for each side, in the order top, right, bottom, left.
+    pub fn new(top: usize, right: usize, bottom: usize, left: usize) -> Padding {
+        Padding {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// The same padding on every side.
+    pub fn all(pad: usize) -> Padding {
+        Padding::new(pad, pad, pad, pad)
+    }
+
+    /// One padding above and below the content, another to its left and right.
+    pub fn symmetric(vertical: usize, horizontal: usize) -> Padding {
+        Padding::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Total padding to the left and right of the content.
+    pub fn horizontal(&self) -> usize {
+        self.left + self.right
+    }
+}
+
+/// A single number pads `n` columns to the left and right of the content and `n / 2`
+/// rows above and below it, since terminal cells are about twice as tall as they are
+/// wide. Use [Padding::all](struct.Padding.html#method.all) for the same padding on
+/// every side.
+impl From<usize> for Padding {
+    fn from(pad: usize) -> Padding {
+        Padding::symmetric(pad / 2, pad)
+    }
+}
+
+impl From<(usize, usize)> for Padding {
+    fn from((vertical, horizontal): (usize, usize)) -> Padding {
+        Padding::symmetric(vertical, horizontal)
+    }
+}
+
+impl From<(usize, usize, usize)> for Padding {
+    fn from((top, horizontal, bottom): (usize, usize, usize)) -> Padding {
+        Padding::new(top, horizontal, bottom, horizontal)
+    }
+}
+
+impl From<(usize, usize, usize, usize)> for Padding {
+    fn from((top, right, bottom, left): (usize, usize, usize, usize)) -> Padding {
+        Padding::new(top, right, bottom, left)
+    }
+}
+
+/// Space outside the border of the box on each side. Margins share the constructors
+/// and shorthand forms of [Padding](struct.Padding.html).
+pub type Margin = Padding;
+
+/// Columns between tab stops unless the box sets its own.
+pub const DEFAULT_TAB_WIDTH: usize = 8;
+
+#[derive(Clone)]
 pub struct Formatting {
-    pub padding: usize,
+    pub padding: Padding,
+    pub margin: Margin,
     pub alignment: Alignment,
+    pub width: Width,
+
+    /// Columns the box wraps at, settled from `width` each time the box is rendered.
     pub max_width: usize,
-    pub padding_left: Option<usize>,
-    pub padding_right: Option<usize>,
-    pub padding_top: Option<usize>,
-    pub padding_bottom: Option<usize>,
+    pub min_width: usize,
+    pub exact_width: Option<usize>,
+    pub min_height: usize,
+    pub exact_height: Option<usize>,
+    pub wrap_mode: WrapMode,
+    pub overflow: Overflow,
+    pub max_lines: Option<usize>,
+    pub tab_width: usize,
+
+    /// Marks content cut off to fit an exact width, height or number of lines.
+    pub ellipsis: String,
 }
 
 impl Formatting {
     pub fn new() -> Formatting {
         Formatting {
-            padding: 2,
+            padding: Padding::from(2),
+            margin: Margin::all(0),
             alignment: Alignment::Left,
+            width: Width::Fixed(80),
             max_width: 80,
-            padding_left: None,
-            padding_right: None,
-            padding_top: None,
-            padding_bottom: None,
+            min_width: 0,
+            exact_width: None,
+            min_height: 0,
+            exact_height: None,
+            wrap_mode: WrapMode::Word,
+            overflow: Overflow::Wrap,
+            max_lines: None,
+            tab_width: DEFAULT_TAB_WIDTH,
+            ellipsis: String::from("…"),
         }
     }
 }
@@ -1,44 +1,228 @@
 /// Helper functions to facilitate line box formatting
+use std::borrow::Cow;
 use std::cmp::max;
+use std::fmt;
 
-/// Set a uniform line length. Line length is no more than max_width.
-pub fn normalize_lines(message: &String, max_width: usize, padding: usize) -> String {
-    let mut normalized_message = String::new();
-    let mut message_lines = message.lines();
-    let mut current = message_lines.next();
-
-    while let Some(line) = current {
-        if line.len() > max_width {
-            let (line1, line2) = line.split_at(max_width - padding - 2);
-            normalized_message += line1;
-            normalized_message += "\n";
-            current = Some(line2);
-        } else {
-            normalized_message += line;
-            normalized_message += "\n";
-            current = message_lines.next();
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::ansi::{self, Segment};
+use crate::color::RESET_CODE;
+use crate::formatting::{Alignment, Overflow, TruncatePosition, WrapMode};
+use crate::width;
+use crate::wrap;
+
+/// Set a uniform line length. Line length is no more than max_width, less the
+/// `reserved` columns taken up by padding and the border lines. Longer lines are
+/// wrapped, truncated or clipped depending on the `overflow` policy. Tabs are
+/// expanded to stops every `tab_width` columns from the start of each row. Returns
+/// the rows.
+pub fn normalize_lines(
+    message: &str,
+    max_width: usize,
+    reserved: usize,
+    wrap_mode: &WrapMode,
+    overflow: &Overflow,
+    alignment: &Alignment,
+    tab_width: usize,
+) -> Vec<String> {
+    let available_width = max_width.saturating_sub(reserved).max(1);
+
+    // Remember which rows end a paragraph, justified text leaves those ragged.
+    let mut rows = Vec::new();
+    for line in message.lines() {
+        let mut wrapped = match overflow {
+            Overflow::Wrap => wrap::wrap_line(line, available_width, wrap_mode, tab_width),
+            Overflow::Clip => wrap::wrap_line(line, available_width, &WrapMode::NoWrap, tab_width),
+            // a truncated line is a single row, so its tabs are expanded from the start
+            // of the line before it's cut
+            Overflow::Truncate { ellipsis, position } => vec![truncate_at(
+                &expand_tabs(line, tab_width),
+                available_width,
+                ellipsis,
+                *position,
+            )
+            .into_owned()],
+        };
+        let last = wrapped.pop().unwrap_or_default();
+        rows.extend(wrapped.into_iter().map(|row| (row, false)));
+        rows.push((last, true));
+    }
+
+    let content_width = rows
+        .iter()
+        .map(|(row, _)| width::display_width(row))
+        .max()
+        .unwrap_or(0);
+
+    let rows = rows
+        .into_iter()
+        .map(|(row, paragraph_end)| match alignment {
+            Alignment::Justify if !paragraph_end => wrap::justify_row(&row, content_width),
+            _ => row,
+        })
+        .collect();
+    ansi::carry_styles(rows)
+}
+
+/// Replace each tab with the spaces up to the next stop every `tab_width` columns,
+/// counting columns from the start of the row. A tab width of zero drops tabs. Rows
+/// without tabs are borrowed as they are.
+pub fn expand_tabs(row: &str, tab_width: usize) -> Cow<'_, str> {
+    expand_tabs_from(row, 0, tab_width)
+}
+
+/// Expand the tabs of `text` like [expand_tabs](fn.expand_tabs.html) when `text`
+/// starts `column` columns into the row.
+pub fn expand_tabs_from(text: &str, mut column: usize, tab_width: usize) -> Cow<'_, str> {
+    if !text.contains('\t') {
+        return Cow::Borrowed(text);
+    }
+    let mut expanded = String::new();
+    for segment in ansi::segments(text) {
+        let text = match segment {
+            Segment::Text(text) => text,
+            Segment::Escape(escape) => {
+                expanded += escape;
+                continue;
+            }
+        };
+        for grapheme in text.graphemes(true) {
+            if grapheme == "\t" {
+                let spaces = tab_stop(column, tab_width);
+                expanded += &gen_whitespace(spaces);
+                column += spaces;
+            } else {
+                expanded += grapheme;
+                column += width::grapheme_width(grapheme);
+            }
         }
     }
+    Cow::Owned(expanded)
+}
 
-    // Bauxite doesn't handle the tab character very well so
-    // replace all tab characters with a single space.
-    normalized_message.replace("\t", " ")
+/// Spaces a tab at `column` takes up to reach the next stop every `tab_width` columns
+pub fn tab_stop(column: usize, tab_width: usize) -> usize {
+    match tab_width {
+        0 => 0,
+        tab_width => tab_width - column % tab_width,
+    }
 }
 
-/// Helper function to get the length of the longest line
-pub fn max_line_length(message: &String) -> usize {
+/// Helper function to get the display width of the longest line
+pub fn max_line_length(message: &str) -> usize {
     let mut max_length = 0;
     for line in message.lines() {
-        max_length = max(max_length, line.len())
+        max_length = max(max_length, width::display_width(line))
     }
     max_length
 }
 
+/// Cut a line down to `width` columns, ending it with `ellipsis` when anything was
+/// cut off. Lines that fit are borrowed as they are.
+pub fn truncate<'a>(line: &'a str, width: usize, ellipsis: &'a str) -> Cow<'a, str> {
+    truncate_at(line, width, ellipsis, TruncatePosition::End)
+}
+
+/// Cut a line down to `width` columns, replacing the part cut out at `position` with
+/// `ellipsis`. Lines that fit are borrowed as they are.
+pub fn truncate_at<'a>(
+    line: &'a str,
+    width: usize,
+    ellipsis: &'a str,
+    position: TruncatePosition,
+) -> Cow<'a, str> {
+    if width::display_width(line) <= width {
+        return Cow::Borrowed(line);
+    }
+    let ellipsis_width = width::display_width(ellipsis);
+    if ellipsis_width >= width {
+        return Cow::Owned(String::from(take(ellipsis, width)));
+    }
+
+    let kept = width - ellipsis_width;
+    Cow::Owned(match position {
+        TruncatePosition::End => end_with(line, width, ellipsis),
+        TruncatePosition::Start => format!("{}{}", ellipsis, take_end(line, kept)),
+        TruncatePosition::Middle => {
+            let start = end_with(line, width - kept / 2, ellipsis);
+            format!("{}{}", start, take_end(line, kept / 2))
+        }
+    })
+}
+
+/// End a line with `ellipsis`, cutting the line short so both fit in `width` columns.
+/// Styles cut off with the end of the line are reset before the ellipsis.
+pub fn end_with(line: &str, width: usize, ellipsis: &str) -> String {
+    let ellipsis_width = width::display_width(ellipsis);
+    if ellipsis_width >= width {
+        return String::from(take(ellipsis, width));
+    }
+    let head = take(line, width - ellipsis_width);
+    let reset = if head.contains('\x1B') {
+        RESET_CODE
+    } else {
+        ""
+    };
+    format!("{}{}{}", head, reset, ellipsis)
+}
+
+/// Helper function to get the start of `text` no wider than `width` columns
+fn take(text: &str, width: usize) -> &str {
+    let (head, _) = width::split_at_width(text, width);
+    if width::display_width(head) > width {
+        ""
+    } else {
+        head
+    }
+}
+
+/// Helper function to get the end of `text` no wider than `width` columns, starting
+/// with any styles still active from the part left out
+fn take_end(text: &str, width: usize) -> String {
+    let total = width::display_width(text);
+    if total <= width {
+        return String::from(text);
+    }
+    let (_, mut tail) = width::split_at_width(text, total - width);
+    if width::display_width(tail) > width {
+        // a wide character straddled the split, leave it out too
+        tail = width::split_at_width(tail, 1).1;
+    }
+    let head = &text[..text.len() - tail.len()];
+    ansi::carry_styles(vec![String::from(head), String::from(tail)])
+        .pop()
+        .unwrap_or_default()
+}
+
+/// Cut the padding on two opposite sides down evenly until together they're no more
+/// than `room`
+pub fn shrink_padding(first: &mut usize, second: &mut usize, room: usize) {
+    while *first + *second > room {
+        if *first > *second {
+            *first -= 1;
+        } else {
+            *second -= 1;
+        }
+    }
+}
+
 /// Helper function to get whitespace for padding
 pub fn gen_whitespace(num: usize) -> String {
     (0..num).map(|_| " ").collect::<String>()
 }
 
+/// Helper function to write whitespace for padding without allocating
+pub fn write_whitespace<W: fmt::Write>(out: &mut W, num: usize) -> fmt::Result {
+    const SPACES: &str = "                                                                ";
+    let mut remaining = num;
+    while remaining > 0 {
+        let count = remaining.min(SPACES.len());
+        out.write_str(&SPACES[..count])?;
+        remaining -= count;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,9 +230,171 @@ mod tests {
     #[test]
     fn test_nomralize_lines() {
         let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
-        let expected = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tem\npor incididunt ut labore et dolore magna aliqua.\n";
+        let expected = vec![
+            "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tem",
+            "por incididunt ut labore et dolore magna aliqua.",
+        ];
+
+        let normalized = normalize_lines(
+            message,
+            80,
+            5,
+            &WrapMode::Char,
+            &Overflow::Wrap,
+            &Alignment::Left,
+            8,
+        );
+        assert_eq!(expected, normalized);
+    }
 
-        let normalized = normalize_lines(&String::from(message), 80, 3);
+    #[test]
+    fn test_normalize_wide_lines() {
+        let message = "日本語日本語";
+        let expected = vec!["日本", "語日", "本語"];
+
+        let normalized = normalize_lines(
+            message,
+            9,
+            4,
+            &WrapMode::Char,
+            &Overflow::Wrap,
+            &Alignment::Left,
+            8,
+        );
         assert_eq!(expected, normalized);
     }
+
+    #[test]
+    fn test_normalize_lines_word_wrap() {
+        let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let expected = vec![
+            "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod",
+            "tempor incididunt ut labore et dolore magna aliqua.",
+        ];
+
+        let normalized = normalize_lines(
+            message,
+            80,
+            5,
+            &WrapMode::Word,
+            &Overflow::Wrap,
+            &Alignment::Left,
+            8,
+        );
+        assert_eq!(expected, normalized);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("status", 6, "…"), "status");
+        assert_eq!(truncate("status", 5, "…"), "stat…");
+        assert_eq!(truncate("日本語", 4, "…"), "日…");
+        assert_eq!(truncate("status", 3, "..."), "...");
+        assert_eq!(truncate("status", 2, "..."), "..");
+        assert_eq!(
+            truncate("\x1B[31mstatus\x1B[0m", 4, "~"),
+            "\x1B[31msta\x1B[0m~"
+        );
+    }
+
+    #[test]
+    fn test_truncate_at() {
+        let line = "0123456789";
+        assert_eq!(truncate_at(line, 6, "…", TruncatePosition::Start), "…56789");
+        assert_eq!(
+            truncate_at(line, 6, "…", TruncatePosition::Middle),
+            "012…89"
+        );
+        assert_eq!(
+            truncate_at(line, 7, "...", TruncatePosition::Middle),
+            "01...89"
+        );
+        assert_eq!(
+            truncate_at("日本語", 4, "…", TruncatePosition::Start),
+            "…語"
+        );
+        assert_eq!(
+            truncate_at("\x1B[31mstatus\x1B[0m", 4, "~", TruncatePosition::Start),
+            "~\x1B[31mtus\x1B[0m"
+        );
+    }
+
+    #[test]
+    fn test_normalize_lines_overflow() {
+        let message = "one row per message\nshort";
+        let truncate = Overflow::Truncate {
+            ellipsis: String::from("…"),
+            position: TruncatePosition::End,
+        };
+        let truncated = normalize_lines(
+            message,
+            12,
+            2,
+            &WrapMode::Word,
+            &truncate,
+            &Alignment::Left,
+            8,
+        );
+        assert_eq!(truncated, vec!["one row p…", "short"]);
+
+        let clipped = normalize_lines(
+            message,
+            12,
+            2,
+            &WrapMode::Word,
+            &Overflow::Clip,
+            &Alignment::Left,
+            8,
+        );
+        assert_eq!(clipped, vec!["one row pe", "short"]);
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expand_tabs("a\tbc\td", 4), "a   bc  d");
+        assert_eq!(expand_tabs("\tx", 8), "        x");
+        assert_eq!(expand_tabs("日本\tx", 8), "日本    x");
+        assert_eq!(
+            expand_tabs("\x1B[31mab\x1B[0m\tc", 4),
+            "\x1B[31mab\x1B[0m  c"
+        );
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+        assert!(matches!(
+            expand_tabs("no tabs", 4),
+            Cow::Borrowed("no tabs")
+        ));
+    }
+
+    #[test]
+    fn test_normalize_lines_tab_stops_per_row() {
+        let message = "abcdefg\th";
+        let normalized = normalize_lines(
+            message,
+            6,
+            0,
+            &WrapMode::Char,
+            &Overflow::Wrap,
+            &Alignment::Left,
+            4,
+        );
+        assert_eq!(normalized, vec!["abcdef", "g   h"]);
+    }
+
+    #[test]
+    fn test_end_with() {
+        assert_eq!(end_with("ok", 5, "…"), "ok…");
+        assert_eq!(end_with("status", 5, "…"), "stat…");
+    }
+
+    #[test]
+    fn test_write_whitespace() {
+        let mut out = String::new();
+        write_whitespace(&mut out, 150).unwrap();
+        assert_eq!(out, gen_whitespace(150));
+    }
+
+    #[test]
+    fn test_max_line_length_uses_display_width() {
+        assert_eq!(max_line_length("日本語\ncafe\u{301}"), 6);
+    }
 }
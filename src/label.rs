@@ -0,0 +1,46 @@
+use crate::ansi::{self, Segment};
+use crate::color::Color;
+use crate::formatting::Placement;
+use crate::width;
+
+/// Text embedded in the top or bottom border of the box.
+pub struct Label {
+    pub text: String,
+    pub placement: Placement,
+    pub color: Option<Color>,
+}
+
+impl Label {
+    /// Construct an empty label, which draws a plain border.
+    pub fn new() -> Label {
+        Label {
+            text: String::new(),
+            placement: Placement::Left,
+            color: None,
+        }
+    }
+
+    /// Set the label text. Line breaks, tabs and other control characters would
+    /// break up the border, so each is replaced with a space. Escape sequences are
+    /// left out, the label is colored with its own color instead.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = ansi::segments(text)
+            .filter_map(|segment| match segment {
+                Segment::Text(text) => Some(text),
+                Segment::Escape(_) => None,
+            })
+            .flat_map(str::chars)
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+    }
+
+    /// The shortest border length that fits the label, including the spaces around
+    /// the text and at least one line on either side of it.
+    pub fn min_border_length(&self) -> usize {
+        if self.text.is_empty() {
+            0
+        } else {
+            width::display_width(&self.text) + 4
+        }
+    }
+}
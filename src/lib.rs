@@ -20,56 +20,112 @@
 //! println!("{}", boxed_message);
 //! ```
 
+use std::borrow::Cow;
 use std::fmt;
+use std::io;
+use std::iter;
 
+mod ansi;
 mod color;
 mod formatting;
 mod helper;
+mod label;
 mod lines;
+mod output;
+mod row;
+mod section;
+mod shadow;
+mod table;
+mod terminal;
+mod width;
+mod wrap;
 
+use self::color::gradient::BorderPaint;
 use self::formatting::Formatting;
+use self::lines::border_chars::{self, Junction};
+use self::output::{IoWriter, Output};
+use self::section::{Content, Layout};
 
 pub use self::formatting::Alignment;
+pub use self::formatting::Margin;
+pub use self::formatting::Overflow;
+pub use self::formatting::Padding;
+pub use self::formatting::Placement;
+pub use self::formatting::TruncatePosition;
+pub use self::formatting::VerticalAlignment;
+pub use self::formatting::Width;
+pub use self::formatting::WrapMode;
 pub use color::ansi_color_codes::AnsiColorCode;
+pub use color::gradient::Gradient;
 pub use color::rgb_color::RgbColor;
+pub use color::support::{ColorLevel, ColorSupport};
+pub use color::text_style::TextStyle;
+pub use color::Color;
+pub use color::ParseColorError;
+pub use lines::border_chars::{BorderChars, BorderCharsError};
 pub use lines::line_type::LineType;
+pub use row::{hstack, Row};
+pub use section::Section;
+pub use shadow::Shadow;
+pub use table::Table;
 
 /// Box builder struct that represents your formatted line box.
 pub struct BoxBuilder {
-    message: String,
+    content: Vec<Content>,
     format: Formatting,
-    lines: lines::Lines,
-    color: color::LineColor,
+    lines: BorderChars,
+    sides: lines::sides::Sides,
+    border: BorderPaint,
+    text_style: TextStyle,
+    background: Color,
+    color_support: ColorSupport,
+    title: label::Label,
+    footer: label::Label,
+    shadow: Option<Shadow>,
+    sections: Vec<Section>,
 }
 
 impl BoxBuilder {
     /// Create a new boxed message from a String
     pub fn new(message: String) -> BoxBuilder {
         BoxBuilder {
-            message: message,
+            content: vec![Content::Text(message)],
             format: Formatting::new(),
-            lines: lines::Lines::new(),
-            color: color::LineColor::new(),
+            lines: BorderChars::default(),
+            sides: lines::sides::Sides::new(),
+            border: BorderPaint::Solid(Color::Default),
+            text_style: TextStyle::new(),
+            background: Color::Default,
+            color_support: ColorSupport::Always,
+            title: label::Label::new(),
+            footer: label::Label::new(),
+            shadow: None,
+            sections: Vec::new(),
         }
     }
 
     /// Create new boxed message from a str
     pub fn from(message: &str) -> BoxBuilder {
         BoxBuilder {
-            message: String::from(message),
+            content: vec![Content::Text(String::from(message))],
             format: Formatting::new(),
-            lines: lines::Lines::new(),
-            color: color::LineColor {
-                ansi: None,
-                rgb: None,
-                color8: None,
-            },
+            lines: BorderChars::default(),
+            sides: lines::sides::Sides::new(),
+            border: BorderPaint::Solid(Color::Default),
+            text_style: TextStyle::new(),
+            background: Color::Default,
+            color_support: ColorSupport::Always,
+            title: label::Label::new(),
+            footer: label::Label::new(),
+            shadow: None,
+            sections: Vec::new(),
         }
     }
 
-    /// Set the global padding on the box
-    pub fn padding(mut self, pad: usize) -> Self {
-        self.format.padding = pad;
+    /// Set the padding on every side of the box using [Padding](struct.Padding.html)
+    /// or one of its shorthand forms.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.format.padding = padding.into();
         self
     }
 
@@ -79,39 +135,177 @@ impl BoxBuilder {
         self
     }
 
-    /// Set the maximum width of the box before lines should wrap
+    /// Set the maximum width of the box before lines should wrap, shorthand for
+    /// `width(Width::Fixed(width))`
     pub fn max_width(mut self, width: usize) -> Self {
-        self.format.max_width = width;
+        self.format.width = Width::Fixed(width);
+        self
+    }
+
+    /// Set the narrowest the box may be in columns, border included. Narrower boxes
+    /// are padded on the right.
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.format.min_width = width;
+        self
+    }
+
+    /// Set the width of the box in columns, border included, whatever its content.
+    /// Lines wrap to fit and anything still too long is cut off with the
+    /// [ellipsis](#method.ellipsis). Overrides the [width](#method.width) setting.
+    /// Padding is given up when there's no room left for the content, but the box is
+    /// never narrower than its left and right lines.
+    pub fn exact_width(mut self, width: usize) -> Self {
+        self.format.exact_width = Some(width);
+        self
+    }
+
+    /// Set the fewest rows the box may take up, border included. Shorter boxes are
+    /// padded at the bottom.
+    pub fn min_height(mut self, height: usize) -> Self {
+        self.format.min_height = height;
+        self
+    }
+
+    /// Set the number of rows the box takes up, border included, whatever its
+    /// content. Shorter boxes are padded at the bottom and rows that don't fit are
+    /// cut off from the bottom, ending the last line left with the
+    /// [ellipsis](#method.ellipsis). The padding above and below is given up once
+    /// only one line is left, but the box is never shorter than its top and bottom
+    /// lines and that one line.
+    pub fn exact_height(mut self, height: usize) -> Self {
+        self.format.exact_height = Some(height);
+        self
+    }
+
+    /// Set the text that marks where content was cut off to fit an exact width, exact
+    /// height or [maximum number of lines](#method.max_lines), defaults to `…`
+    pub fn ellipsis(mut self, ellipsis: &str) -> Self {
+        self.format.ellipsis = String::from(ellipsis);
+        self
+    }
+
+    /// Set how wide the box may grow using [Width](enum.Width.html), by default 80
+    /// columns. Use `Width::Terminal` to wrap at the width of the terminal instead.
+    pub fn width<W: Into<Width>>(mut self, width: W) -> Self {
+        self.format.width = width.into();
+        self
+    }
+
+    /// Set how lines longer than the maximum width are wrapped using [WrapMode](enum.WrapMode.html)
+    pub fn wrap_mode(mut self, mode: WrapMode) -> Self {
+        self.format.wrap_mode = mode;
+        self
+    }
+
+    /// Set what happens to lines longer than the width of the box using
+    /// [Overflow](enum.Overflow.html), by default they wrap
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.format.overflow = overflow;
+        self
+    }
+
+    /// Show at most `lines` rows of the message and of each section. Anything more is
+    /// replaced with a last row saying how many lines were left out. A nested box that
+    /// doesn't fit whole is left out whole.
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.format.max_lines = Some(lines);
+        self
+    }
+
+    /// Set the number of columns between tab stops, defaults to 8. Stops are counted
+    /// from the left edge of the content on each row.
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.format.tab_width = width;
+        self
+    }
+
+    /// Set the whitespace outside the border of the box using [Margin](type.Margin.html)
+    /// or one of its shorthand forms.
+    pub fn margin<M: Into<Margin>>(mut self, margin: M) -> Self {
+        self.format.margin = margin.into();
         self
     }
 
-    /// Set the padding on the bottom, overrides the global bottom padding
+    /// Set the padding on the bottom, overrides the bottom of the global padding
     pub fn padding_bottom(mut self, pad: usize) -> Self {
-        self.format.padding_bottom = Some(pad);
+        self.format.padding.bottom = pad;
         self
     }
 
-    /// Set the padding on the top, overrides the global top padding
+    /// Set the padding on the top, overrides the top of the global padding
     pub fn padding_top(mut self, pad: usize) -> Self {
-        self.format.padding_top = Some(pad);
+        self.format.padding.top = pad;
         self
     }
 
-    /// Set the padding on the left, overrides the global left padding
+    /// Set the padding on the left, overrides the left of the global padding
     pub fn padding_left(mut self, pad: usize) -> Self {
-        self.format.padding_left = Some(pad);
+        self.format.padding.left = pad;
         self
     }
 
-    /// Set the padding on the right, overrides the global right padding
+    /// Set the padding on the right, overrides the right of the global padding
     pub fn padding_right(mut self, pad: usize) -> Self {
-        self.format.padding_right = Some(pad);
+        self.format.padding.right = pad;
+        self
+    }
+
+    /// Set the type of lines to draw using [LineType](enum.LineType.html) or your own
+    /// [BorderChars](struct.BorderChars.html)
+    pub fn line_type<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.lines = line_type.into();
+        self
+    }
+
+    /// Show or hide the top line of the box, a hidden line takes up no room.
+    pub fn border_top(mut self, visible: bool) -> Self {
+        self.sides.top.visible = visible;
+        self
+    }
+
+    /// Show or hide the bottom line of the box, a hidden line takes up no room.
+    pub fn border_bottom(mut self, visible: bool) -> Self {
+        self.sides.bottom.visible = visible;
+        self
+    }
+
+    /// Show or hide the left line of the box, a hidden line takes up no room.
+    pub fn border_left(mut self, visible: bool) -> Self {
+        self.sides.left.visible = visible;
+        self
+    }
+
+    /// Show or hide the right line of the box, a hidden line takes up no room.
+    pub fn border_right(mut self, visible: bool) -> Self {
+        self.sides.right.visible = visible;
         self
     }
 
-    /// Set the type of lines to draw using [LineType](enum.LineType.html)
-    pub fn line_type(mut self, line_type: LineType) -> Self {
-        self.lines = lines::resolve_line_type(line_type);
+    /// Set the type of line on the top, overrides the line type of the whole box.
+    /// Corners where lines of different weights meet are joined with mixed glyphs.
+    pub fn line_type_top<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.sides.top.lines = Some(line_type.into());
+        self
+    }
+
+    /// Set the type of line on the bottom, overrides the line type of the whole box.
+    /// Corners where lines of different weights meet are joined with mixed glyphs.
+    pub fn line_type_bottom<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.sides.bottom.lines = Some(line_type.into());
+        self
+    }
+
+    /// Set the type of line on the left, overrides the line type of the whole box.
+    /// Corners where lines of different weights meet are joined with mixed glyphs.
+    pub fn line_type_left<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.sides.left.lines = Some(line_type.into());
+        self
+    }
+
+    /// Set the type of line on the right, overrides the line type of the whole box.
+    /// Corners where lines of different weights meet are joined with mixed glyphs.
+    pub fn line_type_right<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.sides.right.lines = Some(line_type.into());
         self
     }
 
@@ -122,146 +316,599 @@ impl BoxBuilder {
     /// 16-231 are defined by 16 + 36 x r + 6 x g + b (0 <= r, g, b <= 5)
     /// 232-255 are grayscale from black to white in 24 steps
     pub fn color_8(mut self, color: u8) -> Self {
-        self.color.rgb = None;
-        self.color.ansi = None;
-        self.color.color8 = Some(color);
+        self.border = BorderPaint::Solid(Color::Indexed(color));
         self
     }
 
     /// Basic RGB colors.
     pub fn color_rgb(mut self, red: u8, green: u8, blue: u8) -> Self {
-        self.color.ansi = None;
-        self.color.color8 = None;
-        self.color.rgb = Some(RgbColor {
-            red: red,
-            green: green,
-            blue: blue,
-        });
+        self.border = BorderPaint::Solid(Color::Rgb(RgbColor { red, green, blue }));
         self
     }
 
-    /// Simplest ANSI color codes defind by AnsiColorCode enumerated type.
-    pub fn color(mut self, code: AnsiColorCode) -> Self {
-        self.color.rgb = None;
-        self.color.color8 = None;
-        self.color.ansi = Some(code);
+    /// Set the line color using [Color](enum.Color.html) or anything that converts
+    /// into one, like the simplest ANSI color codes defind by AnsiColorCode enumerated type.
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.border = BorderPaint::Solid(color.into());
         self
     }
 
-    /// Boxed message to string.
-    /// Returns the full line boxed message
-    pub fn to_string(&self) -> String {
-        let format = &self.format;
-        let right_padding = format.padding_right.unwrap_or(format.padding);
-        let left_padding = format.padding_left.unwrap_or(format.padding);
-        let total_horizontal_pad = right_padding + left_padding;
+    /// Draw the lines of the box in a [Gradient](struct.Gradient.html) instead of a
+    /// single color. Titles and footers without their own color follow the gradient.
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.border = BorderPaint::Gradient(gradient);
+        self
+    }
 
-        let normalized_message =
-            helper::normalize_lines(&self.message, format.max_width, total_horizontal_pad);
-        let max_line_length = helper::max_line_length(&normalized_message);
+    /// Fill the inside of the box, padding included, with a [Color](enum.Color.html).
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = color.into();
+        self
+    }
 
-        // wrap the message in the box
-        let mut boxed_message = self.gen_top(max_line_length + right_padding + left_padding);
-        boxed_message += &self.gen_top_padding(max_line_length + total_horizontal_pad);
-        boxed_message += &self.wrap_lines(&normalized_message, max_line_length);
-        boxed_message += &self.gen_bottom_padding(max_line_length + right_padding + left_padding);
-        boxed_message += &self.gen_bottom(max_line_length + left_padding + right_padding);
-        boxed_message
-    }
-
-    /// Helper function to build the top of the box
-    fn gen_top(&self, length: usize) -> String {
-        let vertical_line = (0..length)
-            .map(|_| self.lines.horizontal.clone())
-            .collect::<String>();
-        self.color.wrap_color(format!(
-            "{}{}{}\n",
-            self.lines.top_left, vertical_line, self.lines.top_right
-        ))
-    }
-
-    /// Helper function to build the bottom of the box
-    fn gen_bottom(&self, length: usize) -> String {
-        let vertical_line = (0..length)
-            .map(|_| self.lines.horizontal.clone())
-            .collect::<String>();
-        self.color.wrap_color(format!(
-            "{}{}{}",
-            self.lines.bottom_left, vertical_line, self.lines.bottom_right
-        ))
-    }
-
-    /// Wrap the message with the box on it's left and right
-    fn wrap_lines(&self, message: &String, max_length: usize) -> String {
-        message
-            .lines()
-            .map(|line| {
-                let left_padding = self.gen_left_padding(line.len(), &max_length);
-                let right_padding = self.gen_right_padding(line.len(), &max_length);
-                self.color.wrap_color(format!(
-                    "{}{}{}{}{}\n",
-                    self.lines.vertical, left_padding, line, right_padding, self.lines.vertical
-                ))
-            })
-            .collect::<String>()
+    /// Set the colors and attributes of the text inside the box using
+    /// [TextStyle](struct.TextStyle.html). The border keeps its own color.
+    pub fn text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    /// Set which colors are written when the box is rendered using
+    /// [ColorSupport](enum.ColorSupport.html). Defaults to `ColorSupport::Always`, which
+    /// writes colors exactly as they were given. `ColorSupport::Auto` downgrades or drops
+    /// colors stdout can't display, so choose a fixed policy when writing somewhere else
+    /// with [write_to](struct.BoxBuilder.html#method.write_to).
+    pub fn color_support(mut self, support: ColorSupport) -> Self {
+        self.color_support = support;
+        self
+    }
+
+    /// Set a title embedded in the top border of the box.
+    /// The box is widened if the title doesn't fit. Escape sequences are left out of
+    /// the title, use [title_color](#method.title_color) to color it.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title.set_text(title);
+        self
+    }
+
+    /// Set where the title is placed along the top border using [Placement](enum.Placement.html)
+    pub fn title_placement(mut self, placement: Placement) -> Self {
+        self.title.placement = placement;
+        self
+    }
+
+    /// Set the color of the title text, by default the title is drawn in the line color.
+    pub fn title_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.title.color = Some(color.into());
+        self
+    }
+
+    /// Set a footer embedded in the bottom border of the box.
+    /// The box is widened if the footer doesn't fit. Escape sequences are left out of
+    /// the footer, use [footer_color](#method.footer_color) to color it.
+    pub fn footer(mut self, footer: &str) -> Self {
+        self.footer.set_text(footer);
+        self
+    }
+
+    /// Set where the footer is placed along the bottom border using [Placement](enum.Placement.html)
+    pub fn footer_placement(mut self, placement: Placement) -> Self {
+        self.footer.placement = placement;
+        self
+    }
+
+    /// Set the color of the footer text, by default the footer is drawn in the line color.
+    pub fn footer_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.footer.color = Some(color.into());
+        self
+    }
+
+    /// Add a [Section](struct.Section.html) below the message and any sections before
+    /// it, separated by a divider
+    pub fn section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
     }
 
-    /// Helper function to to_string padding left of the content
-    fn gen_left_padding(&self, line_length: usize, max_length: &usize) -> String {
-        let padding = match self.format.alignment {
-            Alignment::Left => self.format.padding,
-            Alignment::Right => self.format.padding + max_length - line_length,
+    /// Add text below the content already in the box, after any nested boxes
+    pub fn text(mut self, text: &str) -> Self {
+        self.content.push(Content::Text(String::from(text)));
+        self
+    }
+
+    /// Nest another box below the content already in the box. The nested box is
+    /// measured and aligned as a single block, is never wider than the inside of this
+    /// box and keeps its own colors.
+    pub fn child(mut self, child: BoxBuilder) -> Self {
+        self.content.push(Content::Child(Box::new(child)));
+        self
+    }
+
+    /// Cast a [Shadow](struct.Shadow.html) one cell right of and below the box.
+    /// The shadow sits inside the margin.
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Write the box to `out` a row at a time, without building the whole box in
+    /// memory first. `Display` renders through this, so
+    /// [to_string](#impl-ToString-for-T) gives the same text.
+    /// ```
+    /// use bauxite::BoxBuilder;
+    ///
+    /// let mut boxed = String::new();
+    /// BoxBuilder::from("streamed").padding(0).render_to(&mut boxed).unwrap();
+    /// assert_eq!(boxed, "┌────────┐\n│streamed│\n└────────┘");
+    /// ```
+    pub fn render_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        self.write_within(usize::MAX, out)
+    }
+
+    /// Write the box to an `io::Write` such as stdout or a file a row at a time.
+    /// Every piece of a row is a separate write, so wrap writers that aren't buffered
+    /// in a `BufWriter`.
+    pub fn write_to<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let mut writer = IoWriter::new(out);
+        self.render_to(&mut writer).map_err(|_| {
+            writer
+                .error
+                .take()
+                .unwrap_or_else(|| io::Error::other("formatter error"))
+        })
+    }
+
+    /// Render the box to a String no wider than its width setting or `limit`
+    /// columns, for nesting inside another box
+    fn render_within(&self, limit: usize) -> String {
+        let mut rendered = String::new();
+        // writing to a String can't fail
+        let _ = self.write_within(limit, &mut rendered);
+        rendered
+    }
+
+    /// Write the box no wider than its width setting or `limit` columns, whichever is
+    /// narrower, with the margin and shadow counted in the limit. Boxes that aren't
+    /// nested have no limit and measure against the terminal.
+    fn write_within<W: fmt::Write>(&self, limit: usize, out: &mut W) -> fmt::Result {
+        let outside = self.format.margin.horizontal() + usize::from(self.shadow.is_some());
+        let available = || match limit {
+            usize::MAX => terminal::width(),
+            limit => limit,
+        };
+        let max_width = match (self.format.exact_width, self.format.width) {
+            (Some(width), _) | (None, Width::Fixed(width)) => {
+                width.min(limit.saturating_sub(outside))
+            }
+            (None, Width::Terminal) | (None, Width::Fill) => available().saturating_sub(outside),
+            (None, Width::Percent(percent)) => {
+                (available() * percent / 100).saturating_sub(outside)
+            }
+        };
+        let format = &Formatting {
+            max_width,
+            ..self.format.clone()
+        };
+        let sides = &self.sides;
+
+        let border = sides.horizontal();
+        let mut sections = vec![Layout::new(
+            &self.content,
+            format.alignment,
+            format.padding,
+            format,
+            border,
+        )];
+        sections.extend(
+            self.sections
+                .iter()
+                .map(|section| section::layout(section, format, border)),
+        );
+
+        // widen the content if the title or footer doesn't fit in the border, or to
+        // fill the width of the terminal
+        let fill = match format.width {
+            Width::Fill => format.max_width.saturating_sub(border),
+            _ => 0,
+        };
+        let length = match format.exact_width {
+            Some(_) => format.max_width.saturating_sub(border),
+            None => sections
+                .iter()
+                .map(Layout::width)
+                .chain(iter::once(
+                    self.title.min_border_length() * sides.top.size(),
+                ))
+                .chain(iter::once(
+                    self.footer.min_border_length() * sides.bottom.size(),
+                ))
+                .chain(iter::once(fill))
+                .chain(iter::once(format.min_width.saturating_sub(border)))
+                .max()
+                .unwrap_or(0),
         };
-        helper::gen_whitespace(padding)
+        self.fit_sections(&mut sections, length);
+
+        let level = self.color_support.level();
+        let background = self
+            .background
+            .downgrade(level)
+            .background_code()
+            .unwrap_or_default();
+        let text_style = self.text_style.codes(level);
+        let frame = Frame {
+            width: length + border,
+            height: sections.iter().map(Layout::height).sum::<usize>() + sections.len() - 1
+                + sides.vertical(),
+            level,
+            lines: sides.border_chars(&self.lines),
+            border_code: match &self.border {
+                BorderPaint::Solid(color) => color.downgrade(level).foreground_code(),
+                BorderPaint::Gradient(_) => None,
+            },
+            after_reset: format!("{}{}", background, text_style),
+            background,
+            text_style,
+        };
+        let shadow = self
+            .shadow
+            .as_ref()
+            .map(|shadow| shadow.paint(frame.width, level));
+
+        // wrap the message in the box
+        let mut output = Output::new(out, format.margin, frame.width, shadow);
+        output.start()?;
+        self.gen_top(length, &frame, &mut output)?;
+        let mut y = sides.top.size();
+        for (index, section) in sections.iter().enumerate() {
+            if index > 0 {
+                self.gen_divider(length, y, &frame, &mut output)?;
+                y += 1;
+            }
+            self.gen_section(section, length, y, &frame, &mut output)?;
+            y += section.height();
+        }
+        self.gen_bottom(length, &frame, &mut output)?;
+        output.finish()
+    }
+
+    /// Helper function to cut lines that don't fit in `length` columns, then cut or
+    /// pad the sections to the exact or minimum height of the box
+    fn fit_sections(&self, sections: &mut Vec<Layout>, length: usize) {
+        for section in sections.iter_mut() {
+            let width = length.saturating_sub(section.padding.horizontal());
+            for line in section.lines.iter_mut() {
+                if let Cow::Owned(cut) = helper::truncate(&line.text, width, &self.format.ellipsis)
+                {
+                    line.text = cut;
+                }
+            }
+        }
+
+        let height = sections.iter().map(Layout::height).sum::<usize>() + sections.len() - 1
+            + self.sides.vertical();
+        let target = self.format.exact_height.unwrap_or(self.format.min_height);
+        if height > target && self.format.exact_height.is_some() {
+            section::cut_rows(sections, height - target, length, &self.format.ellipsis);
+        } else if height < target {
+            if let Some(last) = sections.last_mut() {
+                last.padding.bottom += target - height;
+            }
+        }
+    }
+
+    /// Helper function to write the top of the box
+    fn gen_top<W: fmt::Write>(
+        &self,
+        length: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        if !self.sides.top.visible {
+            return Ok(());
+        }
+        self.gen_border(
+            output.begin_row()?,
+            (
+                self.side_glyph(frame.lines.top_left(), &self.sides.left),
+                frame.lines.top(),
+                self.side_glyph(frame.lines.top_right(), &self.sides.right),
+            ),
+            length,
+            &self.title,
+            0,
+            frame,
+        )?;
+        output.end_row()
+    }
+
+    /// Helper function to write the bottom of the box
+    fn gen_bottom<W: fmt::Write>(
+        &self,
+        length: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        if !self.sides.bottom.visible {
+            return Ok(());
+        }
+        self.gen_border(
+            output.begin_row()?,
+            (
+                self.side_glyph(frame.lines.bottom_left(), &self.sides.left),
+                frame.lines.bottom(),
+                self.side_glyph(frame.lines.bottom_right(), &self.sides.right),
+            ),
+            length,
+            &self.footer,
+            frame.height - 1,
+            frame,
+        )?;
+        output.end_row()
+    }
+
+    /// Helper function to get a glyph of the border, which is left out when the left
+    /// or right side it belongs to is hidden
+    fn side_glyph<'a>(&self, glyph: &'a str, side: &lines::sides::Side) -> &'a str {
+        if side.visible {
+            glyph
+        } else {
+            ""
+        }
     }
 
-    /// Helper function to to_string padding right of the content
-    fn gen_right_padding(&self, line_length: usize, max_length: &usize) -> String {
-        let padding = match self.format.alignment {
-            Alignment::Right => self.format.padding,
-            Alignment::Left => self.format.padding + max_length - line_length,
+    /// Helper function to write a horizontal border on row `y` from its corner and
+    /// line glyphs with an optional label embedded in it
+    fn gen_border<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        (left, line, right): (&str, &str, &str),
+        length: usize,
+        label: &label::Label,
+        y: usize,
+        frame: &Frame,
+    ) -> fmt::Result {
+        // paint a run of glyphs made of `start`, `count` border lines and `end`
+        let paint = |out: &mut W, (start, count, end): (&str, usize, &str), x: usize| {
+            let glyphs = iter::once(start)
+                .chain(iter::repeat_n(line, count))
+                .chain(iter::once(end));
+            self.border
+                .paint_to(out, glyphs, x, y, (frame.width, frame.height), frame.level)
         };
-        helper::gen_whitespace(padding)
-    }
-
-    /// Helper function to to_string top and bottom padding of the box
-    fn gen_top_padding(&self, length: usize) -> String {
-        let top_padding = self.format.padding_top.unwrap_or(self.format.padding / 2);
-        (0..top_padding)
-            .map(|_| {
-                format!(
-                    "{}{}{}\n",
-                    self.lines.vertical,
-                    helper::gen_whitespace(length),
-                    self.lines.vertical
-                )
-            })
-            .collect::<String>()
-    }
-
-    /// Helper function to to_string top and bottom padding of the box
-    fn gen_bottom_padding(&self, length: usize) -> String {
-        let bottom_padding = self
-            .format
-            .padding_bottom
-            .unwrap_or(self.format.padding / 2);
-        (0..bottom_padding)
-            .map(|_| {
-                format!(
-                    "{}{}{}\n",
-                    self.lines.vertical,
-                    helper::gen_whitespace(length),
-                    self.lines.vertical
-                )
-            })
-            .collect::<String>()
+
+        // cut the label short when the box has an exact width it doesn't fit in
+        let text = helper::truncate(&label.text, length.saturating_sub(4), &self.format.ellipsis);
+        if text.is_empty() {
+            return paint(out, (left, length, right), 0);
+        }
+
+        let label_width = width::display_width(&text);
+        let corner_width = width::display_width(left);
+        let remaining = length.saturating_sub(label_width + 2);
+        let (before, after) = match label.placement {
+            Placement::Left => (1, remaining.saturating_sub(1)),
+            Placement::Center => (remaining / 2, remaining - remaining / 2),
+            Placement::Right => (remaining.saturating_sub(1), 1),
+        };
+        paint(out, (left, before, " "), 0)?;
+        match label.color.or_else(|| self.border.solid()) {
+            Some(color) => {
+                let color = color.downgrade(frame.level);
+                color.write_foreground(out)?;
+                out.write_str(&text)?;
+                if color != Color::Default {
+                    out.write_str(color::RESET_CODE)?;
+                }
+            }
+            None => paint(out, (&text, 0, ""), corner_width + before + 1)?,
+        }
+        paint(
+            out,
+            (" ", after, right),
+            corner_width + before + label_width + 1,
+        )
+    }
+
+    /// Helper function to write row `y` of the box, drawing the interior with
+    /// `interior` over the background between the left and right lines
+    fn gen_row<W, F>(
+        &self,
+        y: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+        interior: F,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+        F: FnOnce(&mut W) -> fmt::Result,
+    {
+        let left = self.side_glyph(frame.lines.left(), &self.sides.left);
+        let right = self.side_glyph(frame.lines.right(), &self.sides.right);
+        let out = output.begin_row()?;
+        self.paint_to(out, left, 0, y, frame)?;
+        out.write_str(&frame.background)?;
+        interior(out)?;
+        if !frame.background.is_empty() {
+            out.write_str(color::RESET_CODE)?;
+        }
+        self.paint_to(out, right, frame.width.saturating_sub(1), y, frame)?;
+        output.end_row()
+    }
+
+    /// Helper function to write a divider between two sections on row `y`, joined to
+    /// the left and right lines of the box
+    fn gen_divider<W: fmt::Write>(
+        &self,
+        length: usize,
+        y: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        let line = self.lines.top();
+        let left = border_chars::junction(line, frame.lines.left(), Junction::Left)
+            .unwrap_or_else(|| String::from(self.lines.top_left()));
+        let right = border_chars::junction(line, frame.lines.right(), Junction::Right)
+            .unwrap_or_else(|| String::from(self.lines.top_right()));
+        let glyphs = iter::once(self.side_glyph(&left, &self.sides.left))
+            .chain(iter::repeat_n(line, length))
+            .chain(iter::once(self.side_glyph(&right, &self.sides.right)));
+        self.border.paint_to(
+            output.begin_row()?,
+            glyphs,
+            0,
+            y,
+            (frame.width, frame.height),
+            frame.level,
+        )?;
+        output.end_row()
+    }
+
+    /// Helper function to write the rows of a section starting on row `first_row`
+    fn gen_section<W: fmt::Write>(
+        &self,
+        section: &Layout,
+        length: usize,
+        first_row: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        let padding = &section.padding;
+        let content_row = first_row + padding.top;
+        let bottom_row = content_row + section.lines.len();
+        self.gen_padding(padding.top, length, first_row, frame, output)?;
+        self.wrap_lines(
+            section,
+            length.saturating_sub(padding.horizontal()),
+            content_row,
+            frame,
+            output,
+        )?;
+        self.gen_padding(padding.bottom, length, bottom_row, frame, output)
+    }
+
+    /// Wrap the lines of a section with the box on it's left and right
+    fn wrap_lines<W: fmt::Write>(
+        &self,
+        section: &Layout,
+        max_length: usize,
+        first_row: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        for (index, line) in section.lines.iter().enumerate() {
+            let line_length = width::display_width(&line.text);
+            let left_padding = self.left_padding(section, line_length, max_length);
+            let right_padding = self.right_padding(section, line_length, max_length);
+            self.gen_row(first_row + index, frame, output, |out| {
+                helper::write_whitespace(out, left_padding)?;
+                if line.nested || frame.text_style.is_empty() || line.text.is_empty() {
+                    ansi::write_restyled(out, &line.text, &frame.background)?;
+                } else {
+                    out.write_str(&frame.text_style)?;
+                    ansi::write_restyled(out, &line.text, &frame.after_reset)?;
+                    out.write_str(color::RESET_CODE)?;
+                    out.write_str(&frame.background)?;
+                }
+                helper::write_whitespace(out, right_padding)
+            })?;
+        }
+        Ok(())
     }
+
+    /// Helper function to get the padding left of the content
+    fn left_padding(&self, section: &Layout, line_length: usize, max_length: usize) -> usize {
+        let left = section.padding.left;
+        match section.alignment {
+            Alignment::Left | Alignment::Justify => left,
+            Alignment::Right => left + max_length - line_length,
+            Alignment::Center => left + (max_length - line_length) / 2,
+        }
+    }
+
+    /// Helper function to get the padding right of the content
+    fn right_padding(&self, section: &Layout, line_length: usize, max_length: usize) -> usize {
+        let right = section.padding.right;
+        match section.alignment {
+            Alignment::Right => right,
+            Alignment::Left | Alignment::Justify => right + max_length - line_length,
+            Alignment::Center => {
+                let remaining = max_length - line_length;
+                right + remaining - remaining / 2
+            }
+        }
+    }
+
+    /// Helper function to write blank rows of padding starting on row `first_row`
+    fn gen_padding<W: fmt::Write>(
+        &self,
+        rows: usize,
+        length: usize,
+        first_row: usize,
+        frame: &Frame,
+        output: &mut Output<W>,
+    ) -> fmt::Result {
+        for index in 0..rows {
+            self.gen_row(first_row + index, frame, output, |out| {
+                helper::write_whitespace(out, length)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to write glyphs of the border in the line color. Solid colors
+    /// are written straight to `out`, gradients are painted first.
+    fn paint_to<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        glyphs: &str,
+        x: usize,
+        y: usize,
+        frame: &Frame,
+    ) -> fmt::Result {
+        if glyphs.is_empty() {
+            return Ok(());
+        }
+        match (&self.border, &frame.border_code) {
+            (BorderPaint::Solid(_), Some(code)) => {
+                out.write_str(code)?;
+                out.write_str(glyphs)?;
+                out.write_str(color::RESET_CODE)
+            }
+            (BorderPaint::Solid(_), None) => out.write_str(glyphs),
+            (BorderPaint::Gradient(_), _) => self.border.paint_to(
+                out,
+                iter::once(glyphs),
+                x,
+                y,
+                (frame.width, frame.height),
+                frame.level,
+            ),
+        }
+    }
+}
+
+/// Size of the box being rendered, the glyphs of each side, the color level it is
+/// drawn at and the escape codes of its colors at that level
+struct Frame {
+    width: usize,
+    height: usize,
+    level: ColorLevel,
+    lines: BorderChars,
+
+    /// Color code of a solid line color, `None` for gradients and the default color.
+    border_code: Option<String>,
+    background: String,
+    text_style: String,
+
+    /// Codes that start the background and text style again after a reset in the text.
+    after_reset: String,
 }
 
 /// Implement fmt for BoxBuilder so we can use pass a BoxBuilder to `println!` for printing
 impl fmt::Display for BoxBuilder {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_fmt(format_args!("{}", self.to_string()))
+        self.render_to(formatter)
     }
 }
 
@@ -309,6 +956,839 @@ mod tests {
         assert_eq!(expected, boxed_content.to_string());
     }
 
+    #[test]
+    fn test_center_align() {
+        let expected = "┌────────────────────────────────────┐\n\
+                        │                                    │\n\
+                        │    Lorem ipsum dolor sit amet,     │\n\
+                        │    consectetur adipiscing elit,    │\n\
+                        │  sed do eiusmod tempor incididunt  │\n\
+                        │                                    │\n\
+                        └────────────────────────────────────┘";
+        let message = "Lorem ipsum dolor sit amet,\nconsectetur adipiscing elit,\nsed do eiusmod tempor incididunt";
+        let boxed_content = BoxBuilder::new(String::from(message)).alignment(Alignment::Center);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_justify_align() {
+        let expected = "┌─────────────────────────┐\n\
+                        │                         │\n\
+                        │  Lorem ipsum dolor sit  │\n\
+                        │  amet,  sed do eiusmod  │\n\
+                        │  tempor.                │\n\
+                        │                         │\n\
+                        └─────────────────────────┘";
+        let message = "Lorem ipsum dolor sit amet, sed do eiusmod tempor.";
+        let boxed_content = BoxBuilder::new(String::from(message))
+            .alignment(Alignment::Justify)
+            .max_width(28);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_asymmetric_padding() {
+        let expected = "┌────────────┐\n\
+                        │            │\n\
+                        │ whatever   │\n\
+                        │ whatever   │\n\
+                        └────────────┘";
+        let boxed_content =
+            BoxBuilder::new(String::from("whatever\nwhatever")).padding((1, 3, 0, 1));
+        assert_eq!(expected, boxed_content.to_string());
+
+        let boxed_content = BoxBuilder::new(String::from("whatever\nwhatever"))
+            .padding_left(1)
+            .padding_right(3)
+            .padding_bottom(0);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_padding_all() {
+        let expected = "┌──────────┐\n\
+                        │          │\n\
+                        │          │\n\
+                        │  x       │\n\
+                        │  yyyyyy  │\n\
+                        │          │\n\
+                        │          │\n\
+                        └──────────┘";
+        let boxed_content = BoxBuilder::from("x\nyyyyyy").padding(Padding::all(2));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_margin() {
+        let expected = "                  \n\
+                        \x20   ┌────────┐    \n\
+                        \x20   │        │    \n\
+                        \x20   │  text  │    \n\
+                        \x20   │        │    \n\
+                        \x20   └────────┘    ";
+        let boxed_content = BoxBuilder::from("text").margin((1, 4, 0, 4));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_colored_message() {
+        let expected = "┌─────────────────┐\n\
+                        │                 │\n\
+                        │  \x1B[31mred\x1B[0m and plain  │\n\
+                        │                 │\n\
+                        └─────────────────┘";
+        let boxed_content = BoxBuilder::from("\x1B[31mred\x1B[0m and plain");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_wrapped_colored_message() {
+        let expected = "┌───────────────┐\n\
+                        │               │\n\
+                        │  plain \x1B[32mgreen\x1B[0m  │\n\
+                        │  \x1B[32mstill green\x1B[0m  │\n\
+                        │  \x1B[32mgreen\x1B[0m plain  │\n\
+                        │               │\n\
+                        └───────────────┘";
+        let message = "plain \x1B[32mgreen still green green\x1B[0m plain";
+        let boxed_content = BoxBuilder::from(message).max_width(18);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_border_color_and_text_style() {
+        let red = |text: &str| format!("\x1B[31m{}\x1B[0m", text);
+        let expected = [
+            red("┌────────┐"),
+            format!("{}        {}", red("│"), red("│")),
+            format!("{}  \x1B[1m\x1B[32mtext\x1B[0m  {}", red("│"), red("│")),
+            format!("{}        {}", red("│"), red("│")),
+            red("└────────┘"),
+        ]
+        .join("\n");
+        let boxed_content = BoxBuilder::from("text")
+            .color(AnsiColorCode::Red)
+            .text_style(TextStyle::new().bold().foreground(AnsiColorCode::Green))
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_colors_written_by_default() {
+        let boxed_content = BoxBuilder::from("text")
+            .padding(0)
+            .color(AnsiColorCode::Red);
+        assert_eq!(
+            boxed_content.to_string(),
+            "\x1B[31m┌────┐\x1B[0m\n\x1B[31m│\x1B[0mtext\x1B[31m│\x1B[0m\n\x1B[31m└────┘\x1B[0m"
+        );
+    }
+
+    #[test]
+    fn test_background_fill() {
+        let blue = |text: &str| format!("\x1B[44m{}\x1B[0m", text);
+        let expected = [
+            String::from("┌────────┐"),
+            format!("│{}│", blue("        ")),
+            format!("│{}│", blue("  text  ")),
+            format!("│{}│", blue("        ")),
+            String::from("└────────┘"),
+        ]
+        .join("\n");
+        let boxed_content = BoxBuilder::from("text")
+            .background(AnsiColorCode::Blue)
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_background_fill_with_text_style() {
+        let expected = "│\x1B[48;2;1;2;3m  \x1B[1mtext\x1B[0m\x1B[48;2;1;2;3m  \x1B[0m│";
+        let boxed_content = BoxBuilder::from("text")
+            .background((1, 2, 3))
+            .text_style(TextStyle::new().bold())
+            .color_support(ColorSupport::Always);
+        assert_eq!(Some(expected), boxed_content.to_string().lines().nth(2));
+
+        let expected = "│\x1B[48;5;214m        \x1B[0m│";
+        let boxed_content = BoxBuilder::from("text")
+            .background(214)
+            .color_support(ColorSupport::Always);
+        assert_eq!(Some(expected), boxed_content.to_string().lines().nth(1));
+    }
+
+    #[test]
+    fn test_downgraded_colors() {
+        let boxed_content = BoxBuilder::from("text")
+            .color_rgb(200, 190, 10)
+            .background((0, 0, 255))
+            .color_support(ColorSupport::Level(ColorLevel::Ansi256));
+        let expected = "\x1B[38;5;178m│\x1B[0m\x1B[48;5;21m        \x1B[0m\x1B[38;5;178m│\x1B[0m";
+        assert_eq!(Some(expected), boxed_content.to_string().lines().nth(1));
+
+        let boxed_content = BoxBuilder::from("text")
+            .color_rgb(200, 190, 10)
+            .background((0, 0, 255))
+            .color_support(ColorSupport::Level(ColorLevel::Ansi16));
+        let expected = "\x1B[33m│\x1B[0m\x1B[44m        \x1B[0m\x1B[33m│\x1B[0m";
+        assert_eq!(Some(expected), boxed_content.to_string().lines().nth(1));
+    }
+
+    #[test]
+    fn test_gradient_border() {
+        let red = RgbColor {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        let blue = RgbColor {
+            red: 0,
+            green: 0,
+            blue: 255,
+        };
+        let boxed_content = BoxBuilder::from("x")
+            .padding(0)
+            .gradient(Gradient::vertical(vec![red, blue]))
+            .color_support(ColorSupport::Always);
+        let expected = "\x1B[38;2;255;0;0m┌─┐\x1B[0m\n\
+                        \x1B[38;2;128;0;128m│\x1B[0mx\x1B[38;2;128;0;128m│\x1B[0m\n\
+                        \x1B[38;2;0;0;255m└─┘\x1B[0m";
+        assert_eq!(expected, boxed_content.to_string());
+
+        let boxed_content = BoxBuilder::from("x")
+            .padding(0)
+            .title("t")
+            .gradient(Gradient::horizontal(vec![red, red, blue]))
+            .color_support(ColorSupport::Level(ColorLevel::Ansi16));
+        let expected = "\x1B[91m┌─ \x1B[0m\x1B[91mt\x1B[0m\x1B[31m \x1B[0m\x1B[34m─┐\x1B[0m\n\
+                        \x1B[91m│\x1B[0mx    \x1B[34m│\x1B[0m\n\
+                        \x1B[91m└───\x1B[0m\x1B[31m─\x1B[0m\x1B[34m─┘\x1B[0m";
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_never_color() {
+        let boxed_content = BoxBuilder::from("whatever\nwhatever")
+            .color(AnsiColorCode::Red)
+            .background(AnsiColorCode::Blue)
+            .title_color(AnsiColorCode::Green)
+            .text_style(TextStyle::new().bold())
+            .color_support(ColorSupport::Never);
+        let plain = BoxBuilder::from("whatever\nwhatever");
+        assert_eq!(plain.to_string(), boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title() {
+        let expected = "┌─ Build Results ───────────┐\n\
+                        │                           │\n\
+                        │  all 12 passed, 0 failed  │\n\
+                        │                           │\n\
+                        └───────────────────────────┘";
+        let boxed_content = BoxBuilder::from("all 12 passed, 0 failed").title("Build Results");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title_placement() {
+        let expected = "┌── Title ──┐\n\
+                        │           │\n\
+                        │  content  │\n\
+                        │           │\n\
+                        └─ Footer ──┘";
+        let boxed_content = BoxBuilder::from("content")
+            .title("Title")
+            .title_placement(Placement::Center)
+            .footer("Footer")
+            .footer_placement(Placement::Center);
+        assert_eq!(expected, boxed_content.to_string());
+
+        let expected = "┌─── Title ─┐\n\
+                        │           │\n\
+                        │  content  │\n\
+                        │           │\n\
+                        └───── End ─┘";
+        let boxed_content = BoxBuilder::from("content")
+            .title("Title")
+            .title_placement(Placement::Right)
+            .footer("End")
+            .footer_placement(Placement::Right);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title_widens_box() {
+        let expected = "┌─ A much longer title ─┐\n\
+                        │                       │\n\
+                        │  short                │\n\
+                        │                       │\n\
+                        └─ ok ──────────────────┘";
+        let boxed_content = BoxBuilder::from("short")
+            .title("A much longer title")
+            .footer("ok");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title_control_characters() {
+        let expected = "┌─ a b ─────┐\n\
+                        │           │\n\
+                        │  content  │\n\
+                        │           │\n\
+                        └─ c d ─────┘";
+        let boxed_content = BoxBuilder::from("content").title("a\nb").footer("c\td");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title_escape_sequences() {
+        let expected = "┌─ red ─┐\n│  ok   │\n└───────┘";
+        let boxed_content = BoxBuilder::from("ok")
+            .padding((0, 2))
+            .title("\x1B[31mred")
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_title_color() {
+        let expected = "┌─ \x1B[31mTitle\x1B[0m ───┐\n\
+                        │           │\n\
+                        │  content  │\n\
+                        │           │\n\
+                        └───────────┘";
+        let boxed_content = BoxBuilder::from("content")
+            .title("Title")
+            .title_color(AnsiColorCode::Red)
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_border_chars() {
+        let expected = "╭━ Title ━━━━╮\n\
+                        ┃            │\n\
+                        ┃  whatever  │\n\
+                        ┃            │\n\
+                        ╰────────────╯";
+        let chars = BorderChars::from(LineType::Basic)
+            .with_corners('╭', '╮', '╰', '╯')
+            .and_then(|chars| chars.with_horizontals('━', '─'))
+            .and_then(|chars| chars.with_verticals('┃', '│'))
+            .unwrap();
+        let boxed_content = BoxBuilder::from("whatever").line_type(chars).title("Title");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_rounded() {
+        let expected = "╭────────────╮\n\
+                        │            │\n\
+                        │  whatever  │\n\
+                        │            │\n\
+                        ╰────────────╯";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::Rounded);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_ascii() {
+        let expected = "+------------+\n\
+                        |            |\n\
+                        |  whatever  |\n\
+                        |            |\n\
+                        +------------+";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::Ascii);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_heavy_dashed() {
+        let expected = "┏┅┅┅┅┅┅┅┅┅┅┅┅┓\n\
+                        ┇            ┇\n\
+                        ┇  whatever  ┇\n\
+                        ┇            ┇\n\
+                        ┗┅┅┅┅┅┅┅┅┅┅┅┅┛";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::HeavyDashed);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_double_dashed() {
+        let expected = "┏╍╍╍╍╍╍╍╍╍╍╍╍┓\n\
+                        ╏            ╏\n\
+                        ╏  whatever  ╏\n\
+                        ╏            ╏\n\
+                        ┗╍╍╍╍╍╍╍╍╍╍╍╍┛";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::DoubleDashed);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_block() {
+        let expected = "█▀▀▀▀▀▀▀▀▀▀▀▀█\n\
+                        █            █\n\
+                        █  whatever  █\n\
+                        █            █\n\
+                        █▄▄▄▄▄▄▄▄▄▄▄▄█";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::Block);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_thick_thin() {
+        let expected = "┍━━━━━━━━━━━━┑\n\
+                        │            │\n\
+                        │  whatever  │\n\
+                        │            │\n\
+                        ┕━━━━━━━━━━━━┙";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::ThickThin);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_markdown_safe() {
+        let expected = ".------------.\n\
+                        :            :\n\
+                        :  whatever  :\n\
+                        :            :\n\
+                        '------------'";
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::MarkdownSafe);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_line_type_none() {
+        // only the padding shows, the border is blank but keeps its width
+        let blank = " ".repeat(14);
+        let expected = format!("{0}\n{0}\n   whatever   \n{0}\n{0}", blank);
+        let boxed_content = BoxBuilder::from("whatever").line_type(LineType::None);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_left_border_only() {
+        let expected = "│ quoted\n\
+                        │ text  ";
+        let boxed_content = BoxBuilder::from("quoted\ntext")
+            .padding((0, 0, 0, 1))
+            .border_top(false)
+            .border_right(false)
+            .border_bottom(false);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_top_and_bottom_borders_only() {
+        let expected = format!("─ Title ─\n{0}\n  rule   \n{0}\n─────────", " ".repeat(9));
+        let boxed_content = BoxBuilder::from("rule")
+            .title("Title")
+            .border_left(false)
+            .border_right(false);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_per_side_line_types() {
+        let expected = "╒═══════╕\n\
+                        │ mixed │\n\
+                        ┕━━━━━━━┙";
+        let boxed_content = BoxBuilder::from("mixed")
+            .padding((0, 1))
+            .line_type_top(LineType::Double)
+            .line_type_bottom(LineType::Bold);
+        assert_eq!(expected, boxed_content.to_string());
+
+        let expected = "╓───────┒\n\
+                        ║ mixed ┃\n\
+                        ╙───────┚";
+        let boxed_content = BoxBuilder::from("mixed")
+            .padding((0, 1))
+            .line_type_left(LineType::Double)
+            .line_type_right(LineType::Bold);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_shadow_inside_margin() {
+        let expected = "      \n \
+                        ┌─┐  \n \
+                        │x│░ \n \
+                        └─┘░ \n  \
+                        ░░░ \n      ";
+        let boxed_content = BoxBuilder::from("x")
+            .padding(0)
+            .margin((1, 1))
+            .shadow(Shadow::light());
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_colored_shadow() {
+        let expected = "┌─┐ \n\
+                        │x│\x1B[90m▒\x1B[0m\n\
+                        └─┘\x1B[90m▒\x1B[0m\n \
+                        \x1B[90m▒▒▒\x1B[0m";
+        let boxed_content = BoxBuilder::from("x")
+            .padding(0)
+            .shadow(Shadow::medium().color(AnsiColorCode::BrightBlack))
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_sections() {
+        let expected = "┌─────────────────┐\n\
+                        │     Header      │\n\
+                        ├─────────────────┤\n\
+                        │                 │\n\
+                        │  A longer body  │\n\
+                        │  of text        │\n\
+                        │                 │\n\
+                        ├─────────────────┤\n\
+                        │           footer│\n\
+                        └─────────────────┘";
+        let boxed_content = BoxBuilder::from("Header")
+            .alignment(Alignment::Center)
+            .padding(0)
+            .section(
+                Section::new("A longer body\nof text")
+                    .alignment(Alignment::Left)
+                    .padding((1, 2)),
+            )
+            .section(Section::new("footer").alignment(Alignment::Right));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_section_dividers_join_lines() {
+        let boxed_content = BoxBuilder::from("a")
+            .padding(0)
+            .line_type(LineType::Double)
+            .section(Section::new("b"));
+        assert_eq!("╔═╗\n║a║\n╠═╣\n║b║\n╚═╝", boxed_content.to_string());
+
+        let boxed_content = BoxBuilder::from("a")
+            .padding(0)
+            .line_type_left(LineType::Double)
+            .section(Section::new("b"));
+        assert_eq!("╓─┐\n║a│\n╟─┤\n║b│\n╙─┘", boxed_content.to_string());
+
+        let boxed_content = BoxBuilder::from("a")
+            .padding(0)
+            .line_type(LineType::Ascii)
+            .section(Section::new("b"));
+        assert_eq!("+-+\n|a|\n+-+\n|b|\n+-+", boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_nested_box() {
+        let expected =
+            "┌───────┐\n│outer  │\n│┌─────┐│\n││inner││\n│└─────┘│\n│after  │\n└───────┘";
+        let boxed_content = BoxBuilder::from("outer")
+            .padding(0)
+            .child(BoxBuilder::from("inner").padding(0))
+            .text("after");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_fill_width() {
+        let expected = "┌──────────┐\n│┌────────┐│\n││a       ││\n│└────────┘│\n└──────────┘";
+        let boxed_content = BoxBuilder::from("")
+            .padding(0)
+            .width(12)
+            .child(BoxBuilder::from("a").padding(0).width(Width::Fill));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_min_width() {
+        let expected = "┌──────┐\n│ok    │\n└──────┘";
+        let boxed_content = BoxBuilder::from("ok").padding(0).min_width(8);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_exact_width() {
+        let expected =
+            "┌──────────┐\n│status:   │\n│all       │\n│services  │\n│running   │\n└──────────┘";
+        let boxed_content = BoxBuilder::from("status: all services running")
+            .padding(0)
+            .exact_width(12);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_tiny_exact_width() {
+        for width in 0..=5 {
+            let boxed_content = BoxBuilder::from("ok")
+                .exact_width(width)
+                .title("title")
+                .section(Section::new("body").padding((0, 3)));
+            assert!(boxed_content
+                .to_string()
+                .lines()
+                .all(|line| width::display_width(line) == width.max(2)));
+        }
+
+        for width in 0..=1 {
+            let boxed_content = BoxBuilder::from("ok")
+                .exact_width(width)
+                .border_left(false)
+                .border_right(false);
+            assert!(boxed_content
+                .to_string()
+                .lines()
+                .all(|line| width::display_width(line) == width));
+        }
+
+        let expected = "┌───┐\n│   │\n│ o │\n│ k │\n│   │\n└───┘";
+        assert_eq!(expected, BoxBuilder::from("ok").exact_width(5).to_string());
+    }
+
+    #[test]
+    fn test_exact_width_cuts_title() {
+        let expected = "┌─ A… ─┐\n│ok    │\n└──────┘";
+        let boxed_content = BoxBuilder::from("ok")
+            .padding(0)
+            .exact_width(8)
+            .title("A long title");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_min_height() {
+        let expected = "┌──┐\n│ok│\n│  │\n│  │\n└──┘";
+        let boxed_content = BoxBuilder::from("ok").padding(0).min_height(5);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_exact_height() {
+        let expected = "┌─────┐\n│one  │\n│two~ │\n└─────┘";
+        let boxed_content = BoxBuilder::from("one\ntwo\nthree\nfour")
+            .padding(0)
+            .exact_height(4)
+            .ellipsis("~");
+        assert_eq!(expected, boxed_content.to_string());
+
+        let expected = "┌─────┐\n│one  │\n│two  │\n│     │\n└─────┘";
+        let boxed_content = BoxBuilder::from("one\ntwo")
+            .padding(0)
+            .exact_height(5)
+            .exact_width(7);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_exact_height_drops_sections() {
+        let expected = "┌────┐\n│head│\n├────┤\n│a   │\n│b…  │\n└────┘";
+        let boxed_content = BoxBuilder::from("head")
+            .padding(0)
+            .section(Section::new("a\nb"))
+            .section(Section::new("c\nd"))
+            .exact_height(6);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_exact_height_drops_nested_boxes() {
+        let boxed_content = || {
+            BoxBuilder::from("head")
+                .padding(0)
+                .child(BoxBuilder::from("a\nb\nc").padding(0))
+                .text("tail")
+        };
+        let expected = "┌────┐\n│head│\n│…   │\n│    │\n│    │\n└────┘";
+        assert_eq!(expected, boxed_content().exact_height(6).to_string());
+
+        let expected = "┌────┐\n│head│\n│┌─┐ │\n││a│ │\n││b│ │\n││c│ │\n│└─┘ │\n│tail│\n└────┘";
+        assert_eq!(expected, boxed_content().exact_height(9).to_string());
+    }
+
+    #[test]
+    fn test_exact_height_below_padding() {
+        let expected = "┌─────────┐\n│  one…   │\n└─────────┘";
+        for height in 0..=3 {
+            let boxed_content = BoxBuilder::from("one\ntwo\nthree")
+                .section(Section::new("four"))
+                .exact_height(height);
+            assert_eq!(expected, boxed_content.to_string());
+        }
+
+        let expected = "┌─────────┐\n│         │\n│  one…   │\n└─────────┘";
+        let boxed_content = BoxBuilder::from("one\ntwo\nthree").exact_height(4);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_overflow_truncate() {
+        let expected = "┌────────────────────┐\n\
+                        │GET /api/users 200 …│\n\
+                        │POST /api/sessions/…│\n\
+                        │ok                  │\n\
+                        └────────────────────┘";
+        let message = "GET /api/users 200 12ms\nPOST /api/sessions/refresh 401 3ms\nok";
+        let boxed_content = BoxBuilder::from(message)
+            .padding(0)
+            .max_width(22)
+            .overflow(Overflow::truncate());
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_overflow_truncate_middle() {
+        let expected = "┌────────────────────┐\n\
+                        │GET /api/...200 12ms│\n\
+                        │POST /api... 401 3ms│\n\
+                        └────────────────────┘";
+        let message = "GET /api/users 200 12ms\nPOST /api/sessions/refresh 401 3ms";
+        let boxed_content =
+            BoxBuilder::from(message)
+                .padding(0)
+                .max_width(22)
+                .overflow(Overflow::Truncate {
+                    ellipsis: String::from("..."),
+                    position: TruncatePosition::Middle,
+                });
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_overflow_clip() {
+        let expected = "┌────────────────────┐\n│GET /api/users 200 1│\n└────────────────────┘";
+        let boxed_content = BoxBuilder::from("GET /api/users 200 12ms")
+            .padding(0)
+            .max_width(22)
+            .overflow(Overflow::Clip);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_max_lines() {
+        let expected = "┌────────────────┐\n│1               │\n│2               │\n│… (3 more lines)│\n└────────────────┘";
+        let boxed_content = BoxBuilder::from("1\n2\n3\n4\n5").padding(0).max_lines(3);
+        assert_eq!(expected, boxed_content.to_string());
+
+        let expected = "┌─┐\n│1│\n│2│\n└─┘";
+        let boxed_content = BoxBuilder::from("1\n2").padding(0).max_lines(2);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_max_lines_keeps_nested_boxes_whole() {
+        let boxed_content = || {
+            BoxBuilder::from("head")
+                .padding(0)
+                .child(BoxBuilder::from("a\nb\nc").padding(0))
+                .text("x\ny")
+        };
+        let expected =
+            "┌────────────────┐\n│head            │\n│… (7 more lines)│\n└────────────────┘";
+        assert_eq!(expected, boxed_content().max_lines(3).to_string());
+
+        let expected = "┌────────────────┐\n\
+                        │head            │\n\
+                        │┌─┐             │\n\
+                        ││a│             │\n\
+                        ││b│             │\n\
+                        ││c│             │\n\
+                        │└─┘             │\n\
+                        │… (2 more lines)│\n\
+                        └────────────────┘";
+        assert_eq!(expected, boxed_content().max_lines(7).to_string());
+    }
+
+    #[test]
+    fn test_tab_stops_after_wrapping() {
+        let expected = "┌──────────┐\n│ab      c │\n│d         │\n└──────────┘";
+        let boxed_content = BoxBuilder::from("ab\tc\td")
+            .padding(0)
+            .max_width(12)
+            .wrap_mode(WrapMode::Char);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_default_width() {
+        let message = "a".repeat(100);
+        let boxed_content = BoxBuilder::from(&message);
+        assert_eq!(
+            BoxBuilder::from(&message).max_width(80).to_string(),
+            boxed_content.to_string()
+        );
+        assert!(boxed_content
+            .to_string()
+            .lines()
+            .all(|line| width::display_width(line) == 80));
+    }
+
+    #[test]
+    fn test_terminal_width_in_nested_box() {
+        let expected = "┌─────┐\n│┌───┐│\n││one││\n││two││\n│└───┘│\n└─────┘";
+        let boxed_content = BoxBuilder::from("").padding(0).max_width(7).child(
+            BoxBuilder::from("one two")
+                .padding(0)
+                .width(Width::Terminal),
+        );
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_percent_width() {
+        let expected = "┌─────┐\n│┌───┐│\n││one││\n││two││\n│└───┘│\n└─────┘";
+        let boxed_content = BoxBuilder::from("")
+            .padding(0)
+            .width(Width::Fixed(12))
+            .child(
+                BoxBuilder::from("one two")
+                    .padding(0)
+                    .width(Width::Percent(50)),
+            );
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_deeply_nested_boxes() {
+        let expected =
+            "┌────────┐\n│┌──────┐│\n││┌────┐││\n│││core│││\n││└────┘││\n│└──────┘│\n└────────┘";
+        let core = BoxBuilder::from("core").padding(0);
+        let middle = BoxBuilder::from("").padding(0).child(core);
+        let boxed_content = BoxBuilder::from("").padding(0).child(middle);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_nested_colored_box_aligned_as_block() {
+        let expected = "┌──────────┐\n\
+                        │title text│\n\
+                        │   \x1B[31m┌─┐\x1B[0m    │\n\
+                        │   \x1B[31m│\x1B[0mx\x1B[31m│\x1B[0m    │\n\
+                        │   \x1B[31m└─┘\x1B[0m    │\n\
+                        └──────────┘";
+        let child = BoxBuilder::from("x")
+            .padding(0)
+            .color(AnsiColorCode::Red)
+            .color_support(ColorSupport::Always);
+        let boxed_content = BoxBuilder::from("title text")
+            .padding(0)
+            .alignment(Alignment::Center)
+            .child(child);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_nested_box_fits_inside_parent() {
+        let expected =
+            "┌───────┐\n│┌─────┐│\n││one  ││\n││two  ││\n││three││\n│└─────┘│\n└───────┘";
+        let boxed_content = BoxBuilder::from("")
+            .padding(0)
+            .max_width(10)
+            .child(BoxBuilder::from("one two three").padding(0));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
     #[test]
     fn test_fmt() {
         let expected = "┌──────────────────────────────────────────────────────────────────────┐\n\
@@ -344,22 +1824,66 @@ mod tests {
              │                                                                              │\n\
              └──────────────────────────────────────────────────────────────────────────────┘";
         let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+        let boxed_content = BoxBuilder::new(String::from(message)).wrap_mode(WrapMode::Char);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn word_wrapping() {
+        let expected =
+            "┌───────────────────────────────────────────────────────────────────────────┐\n\
+             │                                                                           │\n\
+             │  Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod  │\n\
+             │  tempor incididunt ut labore et dolore magna aliqua.                      │\n\
+             │                                                                           │\n\
+             └───────────────────────────────────────────────────────────────────────────┘";
+        let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
         let boxed_content = BoxBuilder::new(String::from(message));
         assert_eq!(expected, boxed_content.to_string());
     }
 
     #[test]
     fn handle_tab_character() {
-        let expected = "┌──────┐\n\
-                        │      │\n\
-                        │      │\n\
-                        │      │\n\
-                        └──────┘";
+        let expected = "┌────────────────────┐\n\
+                        │                    │\n\
+                        │                    │\n\
+                        │                    │\n\
+                        └────────────────────┘";
         let message = "		";
         let boxed_content = BoxBuilder::new(String::from(message));
         assert_eq!(expected, boxed_content.to_string());
     }
 
+    #[test]
+    fn test_tab_width() {
+        let expected = "┌───────────┐\n│PID   CMD  │\n│1     init │\n│4213  cargo│\n└───────────┘";
+        let boxed_content = BoxBuilder::from("PID\tCMD\n1\tinit\n4213\tcargo")
+            .padding(0)
+            .tab_width(6);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_write_to() {
+        let boxed_content = BoxBuilder::from("streamed\nrows")
+            .title("log")
+            .background(AnsiColorCode::Blue)
+            .text_style(TextStyle::new().bold())
+            .color_support(ColorSupport::Always)
+            .margin((1, 2))
+            .shadow(Shadow::light());
+        let mut written = Vec::new();
+        boxed_content.write_to(&mut written).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            boxed_content.to_string()
+        );
+
+        let mut rendered = String::new();
+        boxed_content.render_to(&mut rendered).unwrap();
+        assert_eq!(rendered, boxed_content.to_string());
+    }
+
     #[test]
     fn test_from() {
         let expected = "┌──────────────────────────────────────────────────────────────────────┐\n\
@@ -373,4 +1897,98 @@ mod tests {
         let boxed_content = BoxBuilder::from(message).alignment(Alignment::Left);
         assert_eq!(expected, format!("{}", boxed_content));
     }
+
+    #[test]
+    fn test_cjk_alignment() {
+        let expected = "┌──────────┐\n\
+                        │          │\n\
+                        │  日本語  │\n\
+                        │  abc     │\n\
+                        │          │\n\
+                        └──────────┘";
+        let boxed_content = BoxBuilder::from("日本語\nabc");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_combining_accent_alignment() {
+        let expected = "┌────────┐\n\
+                        │        │\n\
+                        │  cafe\u{301}  │\n\
+                        │  cafe  │\n\
+                        │        │\n\
+                        └────────┘";
+        let boxed_content = BoxBuilder::from("cafe\u{301}\ncafe");
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_emoji_zwj_alignment() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let expected = format!(
+            "┌──────┐\n\
+             │      │\n\
+             │  {}  │\n\
+             │  ab  │\n\
+             │      │\n\
+             └──────┘",
+            family
+        );
+        let boxed_content = BoxBuilder::new(format!("{}\nab", family));
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_wide_line_wrapping() {
+        let expected = "┌──────────┐\n\
+                        │          │\n\
+                        │  日本語  │\n\
+                        │  日本    │\n\
+                        │          │\n\
+                        └──────────┘";
+        let boxed_content = BoxBuilder::from("日本語日本").max_width(12);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    #[test]
+    fn test_wide_line_hyphenation() {
+        let expected = "┌──┐\n│日│\n│本│\n│語│\n│日│\n│本│\n└──┘";
+        let boxed_content = BoxBuilder::from("日本語日本")
+            .padding(0)
+            .max_width(4)
+            .wrap_mode(WrapMode::WordWithHyphenation);
+        assert_eq!(expected, boxed_content.to_string());
+    }
+
+    fn alignment_from_index(index: usize) -> Alignment {
+        match index {
+            0 => Alignment::Left,
+            1 => Alignment::Right,
+            2 => Alignment::Center,
+            _ => Alignment::Justify,
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_rendered_lines_share_width(
+            message in "[a-z 日本語\u{301}\n]{0,120}",
+            top in 0usize..4,
+            right in 0usize..8,
+            bottom in 0usize..4,
+            left in 0usize..8,
+            max_width in 1usize..60,
+            alignment in 0usize..4,
+            title in "[a-z ]{0,30}",
+        ) {
+            let boxed_content = BoxBuilder::new(message)
+                .title(&title)
+                .padding(Padding::new(top, right, bottom, left))
+                .alignment(alignment_from_index(alignment))
+                .max_width(max_width);
+            let rendered = boxed_content.to_string();
+            let widths = rendered.lines().map(width::display_width).collect::<Vec<_>>();
+            proptest::prop_assert!(widths.iter().all(|width| *width == widths[0]), "{}", rendered);
+        }
+    }
 }
@@ -0,0 +1,423 @@
+use std::error::Error;
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::line_type::LineType;
+use crate::width;
+
+/// The eight glyphs the border of a box is drawn with.
+///
+/// Start from a [LineType](enum.LineType.html) preset and swap out the glyphs you
+/// want, or give all eight in reading order: top left, top, top right, left,
+/// right, bottom left, bottom and bottom right. Every glyph must be exactly one
+/// terminal cell wide so the sides of the box stay lined up.
+/// ```
+/// use bauxite::{BorderChars, BoxBuilder, LineType};
+///
+/// let rounded = BorderChars::from(LineType::Basic).with_corners('╭', '╮', '╰', '╯').unwrap();
+/// let ascii = BorderChars::new("+-+||+-+").unwrap();
+/// assert!(BorderChars::new("+-+||+-").is_err());
+///
+/// let boxed = BoxBuilder::from("ascii").padding(0).line_type(ascii);
+/// assert_eq!(boxed.to_string(), "+-----+\n|ascii|\n+-----+");
+/// # let _ = BoxBuilder::from("rounded").line_type(rounded);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BorderChars {
+    top_left: String,
+    top: String,
+    top_right: String,
+    left: String,
+    right: String,
+    bottom_left: String,
+    bottom: String,
+    bottom_right: String,
+}
+
+impl BorderChars {
+    /// Build a set of border glyphs from eight glyphs given in reading order: top
+    /// left, top, top right, left, right, bottom left, bottom and bottom right.
+    pub fn new(glyphs: &str) -> Result<BorderChars, BorderCharsError> {
+        let glyphs = glyphs
+            .graphemes(true)
+            .map(validate)
+            .collect::<Result<Vec<_>, _>>()?;
+        if glyphs.len() != 8 {
+            return Err(BorderCharsError::Count(glyphs.len()));
+        }
+
+        let mut glyphs = glyphs.into_iter();
+        let mut next = || glyphs.next().unwrap_or_default();
+        Ok(BorderChars {
+            top_left: next(),
+            top: next(),
+            top_right: next(),
+            left: next(),
+            right: next(),
+            bottom_left: next(),
+            bottom: next(),
+            bottom_right: next(),
+        })
+    }
+
+    /// Replace the four corner glyphs.
+    pub fn with_corners(
+        mut self,
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+    ) -> Result<Self, BorderCharsError> {
+        self.top_left = validate_char(top_left)?;
+        self.top_right = validate_char(top_right)?;
+        self.bottom_left = validate_char(bottom_left)?;
+        self.bottom_right = validate_char(bottom_right)?;
+        Ok(self)
+    }
+
+    /// Replace the glyphs of the top and bottom lines.
+    pub fn with_horizontals(mut self, top: char, bottom: char) -> Result<Self, BorderCharsError> {
+        self.top = validate_char(top)?;
+        self.bottom = validate_char(bottom)?;
+        Ok(self)
+    }
+
+    /// Replace the glyphs of the left and right lines.
+    pub fn with_verticals(mut self, left: char, right: char) -> Result<Self, BorderCharsError> {
+        self.left = validate_char(left)?;
+        self.right = validate_char(right)?;
+        Ok(self)
+    }
+
+    /// Glyph of the top left corner.
+    pub fn top_left(&self) -> &str {
+        &self.top_left
+    }
+
+    /// Glyph of the top line.
+    pub fn top(&self) -> &str {
+        &self.top
+    }
+
+    /// Glyph of the top right corner.
+    pub fn top_right(&self) -> &str {
+        &self.top_right
+    }
+
+    /// Glyph of the left line.
+    pub fn left(&self) -> &str {
+        &self.left
+    }
+
+    /// Glyph of the right line.
+    pub fn right(&self) -> &str {
+        &self.right
+    }
+
+    /// Glyph of the bottom left corner.
+    pub fn bottom_left(&self) -> &str {
+        &self.bottom_left
+    }
+
+    /// Glyph of the bottom line.
+    pub fn bottom(&self) -> &str {
+        &self.bottom
+    }
+
+    /// Glyph of the bottom right corner.
+    pub fn bottom_right(&self) -> &str {
+        &self.bottom_right
+    }
+}
+
+impl Default for BorderChars {
+    fn default() -> BorderChars {
+        BorderChars::from(LineType::Basic)
+    }
+}
+
+impl From<LineType> for BorderChars {
+    fn from(line_type: LineType) -> BorderChars {
+        let glyphs = match line_type {
+            LineType::Basic => "┌─┐││└─┘",
+            LineType::Dotted => "┌╌┐╎╎└╌┘",
+            LineType::Bold => "┏━┓┃┃┗━┛",
+            LineType::Double => "╔═╗║║╚═╝",
+            LineType::Rounded => "╭─╮││╰─╯",
+            LineType::Ascii => "+-+||+-+",
+            LineType::HeavyDashed => "┏┅┓┇┇┗┅┛",
+            LineType::DoubleDashed => "┏╍┓╏╏┗╍┛",
+            LineType::Block => "█▀████▄█",
+            LineType::ThickThin => "┍━┑││┕━┙",
+            LineType::MarkdownSafe => ".-.::'-'",
+            LineType::None => "        ",
+        };
+        preset(glyphs)
+    }
+}
+
+/// Glyphs of a built in line type, which are known to be valid.
+fn preset(glyphs: &str) -> BorderChars {
+    let mut glyphs = glyphs.chars().map(String::from);
+    let mut next = || glyphs.next().unwrap_or_default();
+    BorderChars {
+        top_left: next(),
+        top: next(),
+        top_right: next(),
+        left: next(),
+        right: next(),
+        bottom_left: next(),
+        bottom: next(),
+        bottom_right: next(),
+    }
+}
+
+/// Weight of a box drawing line.
+#[derive(Clone, Copy, PartialEq)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
+}
+
+/// Weight of a line glyph, if it is a box drawing line.
+fn weight(glyph: &str) -> Option<Weight> {
+    match glyph {
+        "─" | "│" | "╌" | "╎" | "┄" | "┆" | "┈" | "┊" => Some(Weight::Light),
+        "━" | "┃" | "╍" | "╏" | "┅" | "┇" | "┉" | "┋" => Some(Weight::Heavy),
+        "═" | "║" => Some(Weight::Double),
+        _ => None,
+    }
+}
+
+/// Corners joining a horizontal and a vertical line of the given weights, in the
+/// order top left, top right, bottom left and bottom right. Heavy and double lines
+/// have no corners that join them.
+fn corners(horizontal: Weight, vertical: Weight) -> Option<[char; 4]> {
+    match (horizontal, vertical) {
+        (Weight::Light, Weight::Light) => Some(['┌', '┐', '└', '┘']),
+        (Weight::Heavy, Weight::Heavy) => Some(['┏', '┓', '┗', '┛']),
+        (Weight::Double, Weight::Double) => Some(['╔', '╗', '╚', '╝']),
+        (Weight::Heavy, Weight::Light) => Some(['┍', '┑', '┕', '┙']),
+        (Weight::Light, Weight::Heavy) => Some(['┎', '┒', '┖', '┚']),
+        (Weight::Double, Weight::Light) => Some(['╒', '╕', '╘', '╛']),
+        (Weight::Light, Weight::Double) => Some(['╓', '╖', '╙', '╜']),
+        _ => None,
+    }
+}
+
+/// Where a line inside the box meets another line.
+#[derive(Clone, Copy)]
+pub enum Junction {
+    /// A horizontal line leaving the left line, `├`.
+    Left,
+    /// A horizontal line leaving the right line, `┤`.
+    Right,
+    /// A vertical line leaving the top line, `┬`.
+    Top,
+    /// A vertical line leaving the bottom line, `┴`.
+    Bottom,
+    /// A horizontal and a vertical line crossing, `┼`.
+    Cross,
+}
+
+/// Glyph joining a horizontal line glyph and a vertical line glyph at the junction,
+/// if both are box drawing lines and there is a character that joins their weights.
+pub fn junction(horizontal: &str, vertical: &str, junction: Junction) -> Option<String> {
+    let glyphs = match (weight(horizontal)?, weight(vertical)?) {
+        (Weight::Light, Weight::Light) => ['├', '┤', '┬', '┴', '┼'],
+        (Weight::Heavy, Weight::Heavy) => ['┣', '┫', '┳', '┻', '╋'],
+        (Weight::Double, Weight::Double) => ['╠', '╣', '╦', '╩', '╬'],
+        (Weight::Heavy, Weight::Light) => ['┝', '┥', '┯', '┷', '┿'],
+        (Weight::Light, Weight::Heavy) => ['┠', '┨', '┰', '┸', '╂'],
+        (Weight::Double, Weight::Light) => ['╞', '╡', '╤', '╧', '╪'],
+        (Weight::Light, Weight::Double) => ['╟', '╢', '╥', '╨', '╫'],
+        _ => return None,
+    };
+    Some(String::from(glyphs[junction as usize]))
+}
+
+/// Combine the lines of each side into one set of glyphs. Sides without their own
+/// glyphs use `base`.
+///
+/// Where two sides drawn from different glyphs meet, the corner is the box drawing
+/// character that joins their weights, like `╒` for a double top line and a light
+/// left line. If there is no such character the corner of the top or bottom line
+/// is used.
+pub fn join_sides(
+    base: &BorderChars,
+    top: Option<&BorderChars>,
+    right: Option<&BorderChars>,
+    bottom: Option<&BorderChars>,
+    left: Option<&BorderChars>,
+) -> BorderChars {
+    let top = top.unwrap_or(base);
+    let right = right.unwrap_or(base);
+    let bottom = bottom.unwrap_or(base);
+    let left = left.unwrap_or(base);
+
+    let corner = |horizontal: &BorderChars, vertical: &BorderChars, index: usize| {
+        let own = [
+            &horizontal.top_left,
+            &horizontal.top_right,
+            &horizontal.bottom_left,
+            &horizontal.bottom_right,
+        ][index];
+        if horizontal == vertical {
+            return own.clone();
+        }
+        let line = [
+            &horizontal.top,
+            &horizontal.top,
+            &horizontal.bottom,
+            &horizontal.bottom,
+        ];
+        let side = [
+            &vertical.left,
+            &vertical.right,
+            &vertical.left,
+            &vertical.right,
+        ];
+        match (weight(line[index]), weight(side[index])) {
+            (Some(line), Some(side)) => corners(line, side)
+                .map_or_else(|| own.clone(), |corners| String::from(corners[index])),
+            _ => own.clone(),
+        }
+    };
+
+    BorderChars {
+        top_left: corner(top, left, 0),
+        top: top.top.clone(),
+        top_right: corner(top, right, 1),
+        left: left.left.clone(),
+        right: right.right.clone(),
+        bottom_left: corner(bottom, left, 2),
+        bottom: bottom.bottom.clone(),
+        bottom_right: corner(bottom, right, 3),
+    }
+}
+
+/// Check that a glyph takes up exactly one terminal cell.
+fn validate(glyph: &str) -> Result<String, BorderCharsError> {
+    if glyph.chars().any(char::is_control) || width::grapheme_width(glyph) != 1 {
+        return Err(BorderCharsError::Width(String::from(glyph)));
+    }
+    Ok(String::from(glyph))
+}
+
+/// Check that a glyph given as a char takes up exactly one terminal cell.
+pub fn validate_char(glyph: char) -> Result<String, BorderCharsError> {
+    validate(glyph.encode_utf8(&mut [0; 4]))
+}
+
+/// Error returned when border glyphs can't be used to draw a box.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BorderCharsError {
+    /// The wrong number of glyphs was given, eight are needed.
+    Count(usize),
+
+    /// A glyph isn't exactly one terminal cell wide.
+    Width(String),
+}
+
+impl fmt::Display for BorderCharsError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorderCharsError::Count(count) => {
+                write!(formatter, "expected 8 border glyphs, found {}", count)
+            }
+            BorderCharsError::Width(glyph) => {
+                write!(formatter, "border glyph {:?} is not one cell wide", glyph)
+            }
+        }
+    }
+}
+
+impl Error for BorderCharsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let chars = BorderChars::new("╭─╮│┃╰━╯").unwrap();
+        assert_eq!(chars.top_left(), "╭");
+        assert_eq!(chars.top(), "─");
+        assert_eq!(chars.right(), "┃");
+        assert_eq!(chars.bottom(), "━");
+        assert_eq!(chars.bottom_right(), "╯");
+    }
+
+    #[test]
+    fn test_invalid_glyphs() {
+        assert_eq!(BorderChars::new("+-+||+-"), Err(BorderCharsError::Count(7)));
+        assert_eq!(
+            BorderChars::new("+-+|漢+-+"),
+            Err(BorderCharsError::Width(String::from("漢")))
+        );
+        assert_eq!(
+            BorderChars::new("+-+|\t+-+"),
+            Err(BorderCharsError::Width(String::from("\t")))
+        );
+        let error = BorderChars::default().with_verticals('|', '\u{301}');
+        assert_eq!(error, Err(BorderCharsError::Width(String::from("\u{301}"))));
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(
+            BorderChars::from(LineType::Double),
+            BorderChars::new("╔═╗║║╚═╝").unwrap()
+        );
+        assert_eq!(BorderChars::default(), BorderChars::from(LineType::Basic));
+    }
+
+    #[test]
+    fn test_derive_from_preset() {
+        let chars = BorderChars::from(LineType::Basic)
+            .with_corners('╭', '╮', '╰', '╯')
+            .and_then(|chars| chars.with_horizontals('━', '═'))
+            .and_then(|chars| chars.with_verticals('┃', '║'))
+            .unwrap();
+        assert_eq!(chars, BorderChars::new("╭━╮┃║╰═╯").unwrap());
+    }
+
+    #[test]
+    fn test_join_sides() {
+        let base = BorderChars::from(LineType::Rounded);
+        assert_eq!(join_sides(&base, None, None, None, None), base);
+
+        let double = BorderChars::from(LineType::Double);
+        let bold = BorderChars::from(LineType::Bold);
+        let joined = join_sides(&base, Some(&double), Some(&bold), None, None);
+        assert_eq!(joined, BorderChars::new("╒═╗│┃╰─┚").unwrap());
+
+        let ascii = BorderChars::from(LineType::Ascii);
+        let joined = join_sides(&base, None, None, Some(&ascii), Some(&double));
+        assert_eq!(joined, BorderChars::new("╓─╮║│+-+").unwrap());
+    }
+
+    #[test]
+    fn test_junction() {
+        assert_eq!(junction("─", "│", Junction::Left), Some(String::from("├")));
+        assert_eq!(junction("═", "│", Junction::Right), Some(String::from("╡")));
+        assert_eq!(junction("━", "│", Junction::Cross), Some(String::from("┿")));
+        assert_eq!(junction("─", "┃", Junction::Top), Some(String::from("┰")));
+        assert_eq!(junction("━", "║", Junction::Left), None);
+        assert_eq!(junction("-", "|", Junction::Left), None);
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            BorderCharsError::Count(3).to_string(),
+            "expected 8 border glyphs, found 3"
+        );
+        assert_eq!(
+            BorderCharsError::Width(String::from("漢")).to_string(),
+            "border glyph \"漢\" is not one cell wide"
+        );
+    }
+}
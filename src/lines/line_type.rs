@@ -1,4 +1,5 @@
 /// Enumerated type used to change the line type of the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LineType {
     /// Simple lines one line wide.
     /// ```text
@@ -47,4 +48,101 @@ pub enum LineType {
     /// ╚══════════════════════════════════════════════════════════╝
     /// ```
     Double,
+
+    /// Single lines with rounded corners
+    /// ```text
+    /// ╭──────────────────────────────────────────────────────────╮
+    /// │ Lorem ipsum dolor sit amet, consectetur adipiscing elit, │
+    /// │ sed do eiusmod tempor incididunt ut labore et dolore     │
+    /// │ magna aliqua. Ut enim ad minim veniam, quis nostrud      │
+    /// │ exercitation ullamco laboris nisi ut aliquip ex ea       │
+    /// │ commodo consequat.                                       │
+    /// ╰──────────────────────────────────────────────────────────╯
+    /// ```
+    Rounded,
+
+    /// Plain ASCII for terminals and fonts without box drawing characters
+    /// ```text
+    /// +----------------------------------------------------------+
+    /// | Lorem ipsum dolor sit amet, consectetur adipiscing elit, |
+    /// | sed do eiusmod tempor incididunt ut labore et dolore     |
+    /// | magna aliqua. Ut enim ad minim veniam, quis nostrud      |
+    /// | exercitation ullamco laboris nisi ut aliquip ex ea       |
+    /// | commodo consequat.                                       |
+    /// +----------------------------------------------------------+
+    /// ```
+    Ascii,
+
+    /// Bold lines broken into dashes
+    /// ```text
+    /// ┏┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┓
+    /// ┇ Lorem ipsum dolor sit amet, consectetur adipiscing elit, ┇
+    /// ┇ sed do eiusmod tempor incididunt ut labore et dolore     ┇
+    /// ┇ magna aliqua. Ut enim ad minim veniam, quis nostrud      ┇
+    /// ┇ exercitation ullamco laboris nisi ut aliquip ex ea       ┇
+    /// ┇ commodo consequat.                                       ┇
+    /// ┗┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┅┛
+    /// ```
+    HeavyDashed,
+
+    /// Bold lines broken by a double dash, a heavier take on `Dotted`
+    /// ```text
+    /// ┏╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍┓
+    /// ╏ Lorem ipsum dolor sit amet, consectetur adipiscing elit, ╏
+    /// ╏ sed do eiusmod tempor incididunt ut labore et dolore     ╏
+    /// ╏ magna aliqua. Ut enim ad minim veniam, quis nostrud      ╏
+    /// ╏ exercitation ullamco laboris nisi ut aliquip ex ea       ╏
+    /// ╏ commodo consequat.                                       ╏
+    /// ┗╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍┛
+    /// ```
+    DoubleDashed,
+
+    /// Solid blocks, the top and bottom lines are half blocks so they hug the content
+    /// ```text
+    /// █▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀█
+    /// █ Lorem ipsum dolor sit amet, consectetur adipiscing elit, █
+    /// █ sed do eiusmod tempor incididunt ut labore et dolore     █
+    /// █ magna aliqua. Ut enim ad minim veniam, quis nostrud      █
+    /// █ exercitation ullamco laboris nisi ut aliquip ex ea       █
+    /// █ commodo consequat.                                       █
+    /// █▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄█
+    /// ```
+    Block,
+
+    /// Bold top and bottom lines with thin sides
+    /// ```text
+    /// ┍━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┑
+    /// │ Lorem ipsum dolor sit amet, consectetur adipiscing elit, │
+    /// │ sed do eiusmod tempor incididunt ut labore et dolore     │
+    /// │ magna aliqua. Ut enim ad minim veniam, quis nostrud      │
+    /// │ exercitation ullamco laboris nisi ut aliquip ex ea       │
+    /// │ commodo consequat.                                       │
+    /// ┕━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┙
+    /// ```
+    ThickThin,
+
+    /// ASCII that Markdown leaves alone, no `|` to start a table and no line that
+    /// starts with `-` or `+` to make a list or a rule
+    /// ```text
+    /// .----------------------------------------------------------.
+    /// : Lorem ipsum dolor sit amet, consectetur adipiscing elit, :
+    /// : sed do eiusmod tempor incididunt ut labore et dolore     :
+    /// : magna aliqua. Ut enim ad minim veniam, quis nostrud      :
+    /// : exercitation ullamco laboris nisi ut aliquip ex ea       :
+    /// : commodo consequat.                                       :
+    /// '----------------------------------------------------------'
+    /// ```
+    MarkdownSafe,
+
+    /// No visible border, the box takes up the same room but only the padding shows
+    /// ```text
+    ///
+    ///   Lorem ipsum dolor sit amet, consectetur adipiscing elit,
+    ///   sed do eiusmod tempor incididunt ut labore et dolore
+    ///   magna aliqua. Ut enim ad minim veniam, quis nostrud
+    ///   exercitation ullamco laboris nisi ut aliquip ex ea
+    ///   commodo consequat.
+    ///
+    /// ```
+    None,
 }
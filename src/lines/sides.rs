@@ -0,0 +1,63 @@
+use super::border_chars::{self, BorderChars};
+
+/// One side of the border of the box.
+pub struct Side {
+    pub visible: bool,
+    pub lines: Option<BorderChars>,
+}
+
+impl Side {
+    /// Construct a visible side drawn with the lines of the rest of the box.
+    pub fn new() -> Side {
+        Side {
+            visible: true,
+            lines: None,
+        }
+    }
+
+    /// Number of columns or rows the side takes up.
+    pub fn size(&self) -> usize {
+        usize::from(self.visible)
+    }
+}
+
+/// The four sides of the border of the box.
+pub struct Sides {
+    pub top: Side,
+    pub right: Side,
+    pub bottom: Side,
+    pub left: Side,
+}
+
+impl Sides {
+    /// Construct all four sides visible and drawn with the same lines.
+    pub fn new() -> Sides {
+        Sides {
+            top: Side::new(),
+            right: Side::new(),
+            bottom: Side::new(),
+            left: Side::new(),
+        }
+    }
+
+    /// Glyphs to draw each side with, using `base` for sides without their own lines.
+    pub fn border_chars(&self, base: &BorderChars) -> BorderChars {
+        border_chars::join_sides(
+            base,
+            self.top.lines.as_ref(),
+            self.right.lines.as_ref(),
+            self.bottom.lines.as_ref(),
+            self.left.lines.as_ref(),
+        )
+    }
+
+    /// Columns taken up by the left and right lines.
+    pub fn horizontal(&self) -> usize {
+        self.left.size() + self.right.size()
+    }
+
+    /// Rows taken up by the top and bottom lines.
+    pub fn vertical(&self) -> usize {
+        self.top.size() + self.bottom.size()
+    }
+}
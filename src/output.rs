@@ -0,0 +1,161 @@
+//! Streaming the rows of a box to a writer.
+use std::fmt;
+use std::io;
+
+use crate::formatting::Margin;
+use crate::helper;
+
+/// Writes the rows of a box one at a time, surrounding each with the margin and the
+/// shadow so the whole box never has to be held in memory.
+pub struct Output<'a, W> {
+    out: &'a mut W,
+    margin: Margin,
+    width: usize,
+    shadow: Option<ShadowPaint>,
+    lines: usize,
+    rows: usize,
+}
+
+/// The painted glyphs of a shadow, its right edge and the row below the box.
+pub struct ShadowPaint {
+    pub edge: String,
+    pub bottom: String,
+}
+
+impl<'a, W: fmt::Write> Output<'a, W> {
+    /// Start writing a box `width` columns wide, not counting its margin or shadow.
+    pub fn new(
+        out: &'a mut W,
+        margin: Margin,
+        width: usize,
+        shadow: Option<ShadowPaint>,
+    ) -> Output<'a, W> {
+        Output {
+            out,
+            margin,
+            width: width + usize::from(shadow.is_some()),
+            shadow,
+            lines: 0,
+            rows: 0,
+        }
+    }
+
+    /// Write the margin above the box.
+    pub fn start(&mut self) -> fmt::Result {
+        for _ in 0..self.margin.top {
+            self.blank_line()?;
+        }
+        Ok(())
+    }
+
+    /// Start a row of the box, returning the writer to draw the row with.
+    pub fn begin_row(&mut self) -> Result<&mut W, fmt::Error> {
+        self.new_line()?;
+        helper::write_whitespace(self.out, self.margin.left)?;
+        Ok(self.out)
+    }
+
+    /// Finish a row of the box with the shadow and the margin to its right.
+    pub fn end_row(&mut self) -> fmt::Result {
+        if let Some(shadow) = &self.shadow {
+            if self.rows == 0 {
+                self.out.write_char(' ')?;
+            } else {
+                self.out.write_str(&shadow.edge)?;
+            }
+        }
+        self.rows += 1;
+        helper::write_whitespace(self.out, self.margin.right)
+    }
+
+    /// Write the shadow below the box and the margin below that.
+    pub fn finish(&mut self) -> fmt::Result {
+        if let Some(shadow) = self.shadow.take() {
+            self.begin_row()?.write_char(' ')?;
+            self.out.write_str(&shadow.bottom)?;
+            helper::write_whitespace(self.out, self.margin.right)?;
+        }
+        for _ in 0..self.margin.bottom {
+            self.blank_line()?;
+        }
+        Ok(())
+    }
+
+    /// Helper function to separate each line from the one before it
+    fn new_line(&mut self) -> fmt::Result {
+        if self.lines > 0 {
+            self.out.write_char('\n')?;
+        }
+        self.lines += 1;
+        Ok(())
+    }
+
+    /// Helper function to write a line of the margin above or below the box
+    fn blank_line(&mut self) -> fmt::Result {
+        self.new_line()?;
+        let width = self.margin.left + self.width + self.margin.right;
+        helper::write_whitespace(self.out, width)
+    }
+}
+
+/// Adapts an `io::Write` to `fmt::Write`, keeping the io error that stopped the
+/// writing since `fmt::Error` can't carry it.
+pub struct IoWriter<'a, W> {
+    inner: &'a mut W,
+    pub error: Option<io::Error>,
+}
+
+impl<'a, W: io::Write> IoWriter<'a, W> {
+    pub fn new(inner: &'a mut W) -> IoWriter<'a, W> {
+        IoWriter { inner, error: None }
+    }
+}
+
+impl<'a, W: io::Write> fmt::Write for IoWriter<'a, W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.inner.write_all(text.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_margin_and_shadow() {
+        let mut out = String::new();
+        let shadow = ShadowPaint {
+            edge: String::from("░"),
+            bottom: String::from("░░"),
+        };
+        let mut output = Output::new(&mut out, Margin::new(1, 1, 1, 2), 2, Some(shadow));
+        output.start().unwrap();
+        for row in ["┌┐", "└┘"].iter() {
+            output.begin_row().unwrap().push_str(row);
+            output.end_row().unwrap();
+        }
+        output.finish().unwrap();
+        assert_eq!(out, "      \n  ┌┐  \n  └┘░ \n   ░░ \n      ");
+    }
+
+    #[test]
+    fn test_io_error_is_kept() {
+        struct Full;
+        impl io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut full = Full;
+        let mut writer = IoWriter::new(&mut full);
+        assert!(fmt::Write::write_str(&mut writer, "box").is_err());
+        assert_eq!(writer.error.unwrap().kind(), io::ErrorKind::WriteZero);
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt;
+
+use crate::formatting::VerticalAlignment;
+use crate::helper;
+use crate::terminal;
+
+/// Boxes, tables or any other blocks of text printed side by side.
+///
+/// Blocks are measured in terminal columns, so colored borders and wide glyphs
+/// line up. Shorter blocks are padded to the height of the tallest one, and blocks
+/// that don't fit in the width of the terminal wrap onto a new row.
+/// ```
+/// use bauxite::{hstack, BoxBuilder};
+///
+/// let row = hstack(vec![
+///     BoxBuilder::from("up").padding(0),
+///     BoxBuilder::from("down\nload").padding(0),
+/// ]);
+/// assert_eq!(
+///     row.to_string(),
+///     "┌──┐ ┌────┐\n\
+///      │up│ │down│\n\
+///      └──┘ │load│\n\
+///      \x20    └────┘"
+/// );
+/// ```
+pub struct Row {
+    blocks: Vec<String>,
+    gap: usize,
+    alignment: VerticalAlignment,
+    max_width: Option<usize>,
+}
+
+impl Row {
+    /// Create an empty row
+    pub fn new() -> Row {
+        Row {
+            blocks: Vec::new(),
+            gap: 1,
+            alignment: VerticalAlignment::Top,
+            max_width: None,
+        }
+    }
+
+    /// Add a block to the right of the blocks already in the row. Anything that can
+    /// be printed can be added, like a [BoxBuilder](struct.BoxBuilder.html).
+    pub fn push<B: fmt::Display>(mut self, block: B) -> Self {
+        self.blocks.push(block.to_string());
+        self
+    }
+
+    /// Set the number of columns between blocks, defaults to 1
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set where shorter blocks are placed using [VerticalAlignment](enum.VerticalAlignment.html)
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the width blocks wrap at, by default the width of the terminal as found
+    /// for [Width::Terminal](enum.Width.html#variant.Terminal)
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Render the full row
+    fn render(&self) -> String {
+        let max_width = self.max_width.unwrap_or_else(terminal::width);
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|block| (block, helper::max_line_length(block)))
+            .collect::<Vec<_>>();
+
+        // fill each line of blocks until the next block doesn't fit
+        let mut lines: Vec<Vec<(&String, usize)>> = Vec::new();
+        let mut used = 0;
+        for block in blocks {
+            match lines.last_mut() {
+                Some(line) if used + self.gap + block.1 <= max_width => {
+                    used += self.gap + block.1;
+                    line.push(block);
+                }
+                _ => {
+                    used = block.1;
+                    lines.push(vec![block]);
+                }
+            }
+        }
+
+        lines
+            .iter()
+            .map(|line| self.gen_line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Helper function to print one line of blocks side by side, each given with its
+    /// width
+    fn gen_line(&self, blocks: &[(&String, usize)]) -> String {
+        let height = blocks
+            .iter()
+            .map(|(block, _)| block.lines().count())
+            .max()
+            .unwrap_or(0);
+        let gap = helper::gen_whitespace(self.gap);
+
+        let columns = blocks
+            .iter()
+            .map(|(block, width)| {
+                let remaining = height - block.lines().count();
+                let above = match self.alignment {
+                    VerticalAlignment::Top => 0,
+                    VerticalAlignment::Middle => remaining / 2,
+                    VerticalAlignment::Bottom => remaining,
+                };
+                let blank = helper::gen_whitespace(*width);
+                let mut rows = vec![blank.clone(); above];
+                rows.extend(block.lines().map(|line| {
+                    let padding = width - helper::max_line_length(line);
+                    format!("{}{}", line, helper::gen_whitespace(padding))
+                }));
+                rows.resize(height, blank);
+                rows
+            })
+            .collect::<Vec<_>>();
+
+        (0..height)
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column[row].as_str())
+                    .collect::<Vec<_>>()
+                    .join(&gap)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Default for Row {
+    fn default() -> Row {
+        Row::new()
+    }
+}
+
+/// Create a [Row](struct.Row.html) of the given blocks side by side
+pub fn hstack<I, B>(blocks: I) -> Row
+where
+    I: IntoIterator<Item = B>,
+    B: fmt::Display,
+{
+    blocks.into_iter().fold(Row::new(), Row::push)
+}
+
+/// Implement fmt for Row so we can use pass a Row to `println!` for printing
+impl fmt::Display for Row {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "+-+\n|a|\n+-+";
+    const TALL: &str = "+--+\n|bb|\n|bb|\n+--+";
+
+    #[test]
+    fn test_side_by_side() {
+        let expected = "+-+  +--+\n\
+                        |a|  |bb|\n\
+                        +-+  |bb|\n\
+                        \x20    +--+";
+        let row = Row::new().push(SMALL).push(TALL).gap(2).max_width(80);
+        assert_eq!(expected, row.to_string());
+    }
+
+    #[test]
+    fn test_vertical_alignment() {
+        let row = || Row::new().push(SMALL).push(TALL).max_width(80);
+        let middle = row().vertical_alignment(VerticalAlignment::Middle);
+        assert_eq!(middle.to_string(), "+-+ +--+\n|a| |bb|\n+-+ |bb|\n    +--+");
+        let bottom = row().vertical_alignment(VerticalAlignment::Bottom);
+        assert_eq!(bottom.to_string(), "    +--+\n+-+ |bb|\n|a| |bb|\n+-+ +--+");
+    }
+
+    #[test]
+    fn test_wraps_to_new_line() {
+        let expected = "+-+ +--+\n\
+                        |a| |bb|\n\
+                        +-+ |bb|\n\
+                        \x20   +--+\n\
+                        +-+\n\
+                        |a|\n\
+                        +-+";
+        let row = hstack(vec![SMALL, TALL, SMALL]).max_width(8);
+        assert_eq!(expected, row.to_string());
+    }
+
+    #[test]
+    fn test_colored_blocks() {
+        let colored = "\x1B[31m+-+\x1B[0m\n\x1B[31m|a|\x1B[0m";
+        let row = Row::new().push(colored).push("ab").max_width(80);
+        assert_eq!(
+            row.to_string(),
+            "\x1B[31m+-+\x1B[0m ab\n\x1B[31m|a|\x1B[0m   "
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::formatting::{Alignment, Formatting, Padding};
+use crate::helper;
+use crate::width;
+use crate::BoxBuilder;
+
+/// A piece of the content of a box or section, either text that is wrapped to the
+/// width of the box or a box nested inside it.
+pub enum Content {
+    Text(String),
+
+    /// A nested box, kept whole as a block of lines and never wrapped.
+    Child(Box<BoxBuilder>),
+}
+
+/// A part of the box below the message, separated from the part above it by a
+/// divider that joins the left and right lines of the box.
+///
+/// Sections without their own alignment or padding use the alignment and padding
+/// of the box.
+/// ```
+/// use bauxite::{Alignment, BoxBuilder, Section};
+///
+/// let boxed = BoxBuilder::from("Header")
+///     .padding(0)
+///     .alignment(Alignment::Center)
+///     .section(Section::new("body text").alignment(Alignment::Left));
+/// assert_eq!(
+///     boxed.to_string(),
+///     "┌─────────┐\n│ Header  │\n├─────────┤\n│body text│\n└─────────┘"
+/// );
+/// ```
+pub struct Section {
+    content: Vec<Content>,
+    alignment: Option<Alignment>,
+    padding: Option<Padding>,
+}
+
+impl Section {
+    /// Construct a section from a str
+    pub fn new(message: &str) -> Section {
+        Section {
+            content: vec![Content::Text(String::from(message))],
+            alignment: None,
+            padding: None,
+        }
+    }
+
+    /// Set the alignment of the content of this section
+    pub fn alignment(mut self, align: Alignment) -> Self {
+        self.alignment = Some(align);
+        self
+    }
+
+    /// Set the padding of this section using [Padding](struct.Padding.html) or one of
+    /// its shorthand forms.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = Some(padding.into());
+        self
+    }
+
+    /// Add text below the content already in this section
+    pub fn text(mut self, text: &str) -> Self {
+        self.content.push(Content::Text(String::from(text)));
+        self
+    }
+
+    /// Nest a [BoxBuilder](struct.BoxBuilder.html) below the content already in this
+    /// section
+    pub fn child(mut self, child: BoxBuilder) -> Self {
+        self.content.push(Content::Child(Box::new(child)));
+        self
+    }
+}
+
+/// One line of a laid out section.
+pub struct Line {
+    pub text: String,
+
+    /// Whether the line belongs to a nested box, which keeps its own styles.
+    pub nested: bool,
+
+    /// Whether the line is the top line of a nested box. Rows are only cut off above
+    /// it, so nested boxes are kept or dropped whole.
+    pub block_start: bool,
+}
+
+/// A section with its content wrapped to the width of the box and its alignment and
+/// padding settled.
+pub struct Layout {
+    pub lines: Vec<Line>,
+    pub alignment: Alignment,
+    pub padding: Padding,
+}
+
+impl Layout {
+    /// Wrap the content to fit in the maximum width of the box, less its padding and
+    /// the `border` columns taken up by the left and right lines.
+    ///
+    /// Nested boxes are rendered no wider than that and every line of a nested box is
+    /// padded to the width of the box, so the box is aligned as a single block.
+    pub fn new(
+        content: &[Content],
+        alignment: Alignment,
+        mut padding: Padding,
+        format: &Formatting,
+        border: usize,
+    ) -> Layout {
+        // give up padding when the box is too narrow to leave a column for the content
+        helper::shrink_padding(
+            &mut padding.left,
+            &mut padding.right,
+            format.max_width.saturating_sub(border + 1),
+        );
+        let reserved = padding.horizontal() + border;
+        let available = format.max_width.saturating_sub(reserved).max(1);
+        let mut lines = Vec::new();
+        for part in content {
+            match part {
+                Content::Text(message) => {
+                    let rows = helper::normalize_lines(
+                        message,
+                        format.max_width,
+                        reserved,
+                        &format.wrap_mode,
+                        &format.overflow,
+                        &alignment,
+                        format.tab_width,
+                    );
+                    lines.extend(rows.into_iter().map(|text| Line {
+                        text,
+                        nested: false,
+                        block_start: false,
+                    }));
+                }
+                Content::Child(child) => {
+                    let block = child.render_within(available);
+                    let block_width = helper::max_line_length(&block);
+                    lines.extend(block.lines().enumerate().map(|(index, line)| Line {
+                        text: format!(
+                            "{}{}",
+                            line,
+                            helper::gen_whitespace(block_width - width::display_width(line))
+                        ),
+                        nested: true,
+                        block_start: index == 0,
+                    }));
+                }
+            }
+        }
+
+        let mut layout = Layout {
+            lines,
+            alignment,
+            padding,
+        };
+
+        // replace the lines past the maximum with a count of the lines left out,
+        // dropping any nested box that doesn't fit whole
+        if let Some(max_lines) = format.max_lines {
+            let total = layout.lines.len();
+            if total > max_lines {
+                let keep = layout.whole_blocks(max_lines.saturating_sub(1));
+                layout.lines.truncate(keep);
+                let hidden = total - keep;
+                let more = format!(
+                    "{} ({} more {})",
+                    format.ellipsis,
+                    hidden,
+                    if hidden == 1 { "line" } else { "lines" }
+                );
+                layout.lines.push(Line {
+                    text: helper::truncate(&more, available, &format.ellipsis).into_owned(),
+                    nested: false,
+                    block_start: false,
+                });
+            }
+        }
+        layout
+    }
+
+    /// Columns taken up by the widest line and the padding.
+    pub fn width(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| width::display_width(&line.text))
+            .max()
+            .unwrap_or(0)
+            + self.padding.horizontal()
+    }
+
+    /// Rows taken up by the content and the padding.
+    pub fn height(&self) -> usize {
+        self.lines.len() + self.padding.top + self.padding.bottom
+    }
+
+    /// The most lines, no more than `keep`, that can be kept without cutting through
+    /// a nested box.
+    pub fn whole_blocks(&self, keep: usize) -> usize {
+        let mut keep = keep.min(self.lines.len());
+        while keep > 0
+            && keep < self.lines.len()
+            && self.lines[keep].nested
+            && !self.lines[keep].block_start
+        {
+            keep -= 1;
+        }
+        keep
+    }
+}
+
+/// Lay out a section, falling back to the alignment and padding of the box.
+pub fn layout(section: &Section, format: &Formatting, border: usize) -> Layout {
+    Layout::new(
+        &section.content,
+        section.alignment.unwrap_or(format.alignment),
+        section.padding.unwrap_or(format.padding),
+        format,
+        border,
+    )
+}
+
+/// Drop `rows` rows from the bottom of the laid out sections and end the last line
+/// left with `ellipsis`. Whole sections are dropped with their divider while they
+/// fit in the rows to drop, then lines from the end of the last section down to its
+/// first line, then the top and bottom padding of each section. Borders, dividers
+/// and the first line are never dropped, so fewer rows may be dropped than asked for.
+///
+/// Nested boxes are dropped whole, with the ellipsis on a line of its own below the
+/// lines left and the bottom padding making up for any extra rows dropped.
+pub fn cut_rows(sections: &mut Vec<Layout>, mut rows: usize, length: usize, ellipsis: &str) {
+    let mut cut = false;
+    while sections.len() > 1 && sections[sections.len() - 1].height() < rows {
+        let last = sections.pop().unwrap();
+        rows -= last.height() + 1;
+        cut = true;
+    }
+
+    if let Some(last) = sections.last_mut() {
+        let count = last.lines.len();
+        let keep = count.saturating_sub(rows).max(1).min(count);
+        cut |= keep < count;
+        if cut && keep > 0 && last.lines[keep - 1].nested {
+            let keep = last.whole_blocks(keep - 1);
+            last.lines.truncate(keep);
+            last.lines.push(Line {
+                text: String::new(),
+                nested: false,
+                block_start: false,
+            });
+        } else {
+            last.lines.truncate(keep);
+        }
+
+        let dropped = count - last.lines.len();
+        if dropped > rows {
+            last.padding.bottom += dropped - rows;
+        }
+        rows = rows.saturating_sub(dropped);
+    }
+
+    for section in sections.iter_mut().rev() {
+        let padding = &mut section.padding;
+        let vertical = padding.top + padding.bottom;
+        let room = vertical.saturating_sub(rows);
+        rows -= vertical - room;
+        helper::shrink_padding(&mut padding.top, &mut padding.bottom, room);
+    }
+
+    if !cut {
+        return;
+    }
+    let last_line = sections.iter_mut().rev().find_map(|section| {
+        let width = length.saturating_sub(section.padding.horizontal());
+        section.lines.last_mut().map(|line| (line, width))
+    });
+    if let Some((line, width)) = last_line.filter(|(line, _)| !line.nested) {
+        line.text = helper::end_with(&line.text, width, ellipsis);
+    }
+}
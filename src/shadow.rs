@@ -0,0 +1,115 @@
+use crate::color::support::ColorLevel;
+use crate::color::text_style::TextStyle;
+use crate::color::Color;
+use crate::lines::border_chars::{self, BorderCharsError};
+use crate::output::ShadowPaint;
+
+/// A drop shadow cast one cell right of and one cell below the box.
+///
+/// The shadow is drawn with a shade glyph, or with spaces over a dim background
+/// color, and adds one column and one row to the box.
+/// ```
+/// use bauxite::{BoxBuilder, Shadow};
+///
+/// let boxed = BoxBuilder::from("Splash").padding(0).shadow(Shadow::light());
+/// assert_eq!(boxed.to_string(), "┌──────┐ \n│Splash│░\n└──────┘░\n ░░░░░░░░");
+/// ```
+pub struct Shadow {
+    glyph: String,
+    style: TextStyle,
+}
+
+impl Shadow {
+    /// Construct a shadow drawn with the given glyph, which must be one terminal
+    /// cell wide.
+    pub fn new(glyph: char) -> Result<Shadow, BorderCharsError> {
+        Ok(Shadow {
+            glyph: border_chars::validate_char(glyph)?,
+            style: TextStyle::new(),
+        })
+    }
+
+    /// Construct a shadow drawn with the light shade `░`.
+    pub fn light() -> Shadow {
+        Shadow {
+            glyph: String::from("░"),
+            style: TextStyle::new(),
+        }
+    }
+
+    /// Construct a shadow drawn with the medium shade `▒`.
+    pub fn medium() -> Shadow {
+        Shadow {
+            glyph: String::from("▒"),
+            style: TextStyle::new(),
+        }
+    }
+
+    /// Construct a shadow of spaces filled with a background [Color](enum.Color.html),
+    /// usually a dark gray.
+    pub fn dimmed<C: Into<Color>>(background: C) -> Shadow {
+        Shadow {
+            glyph: String::from(" "),
+            style: TextStyle::new().background(background),
+        }
+    }
+
+    /// Set the color of the shadow glyph using [Color](enum.Color.html).
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.style = self.style.foreground(color);
+        self
+    }
+
+    /// Set the color behind the shadow glyph using [Color](enum.Color.html).
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.style = self.style.background(color);
+        self
+    }
+
+    /// Paint the shadow of a box `width` columns wide, the glyph added to the right of
+    /// every line of the box but the first and the row below the box. The row below
+    /// is offset by a blank column, which isn't part of the paint.
+    pub fn paint(&self, width: usize, level: ColorLevel) -> ShadowPaint {
+        ShadowPaint {
+            edge: self.style.wrap_style(&self.glyph, level),
+            bottom: self.style.wrap_style(&self.glyph.repeat(width), level),
+        }
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Shadow {
+        Shadow::light()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ansi_color_codes::AnsiColorCode;
+
+    #[test]
+    fn test_paint() {
+        let paint = Shadow::medium().paint(3, ColorLevel::TrueColor);
+        assert_eq!(paint.edge, "▒");
+        assert_eq!(paint.bottom, "▒▒▒");
+    }
+
+    #[test]
+    fn test_custom_glyph() {
+        let shadow = Shadow::new('#').unwrap();
+        assert_eq!(shadow.paint(3, ColorLevel::TrueColor).bottom, "###");
+        assert!(Shadow::new('漢').is_err());
+    }
+
+    #[test]
+    fn test_dimmed() {
+        let shadow = Shadow::dimmed(AnsiColorCode::BrightBlack);
+        let paint = shadow.paint(3, ColorLevel::TrueColor);
+        assert_eq!(paint.edge, "\x1B[100m \x1B[0m");
+        assert_eq!(paint.bottom, "\x1B[100m   \x1B[0m");
+        let paint = shadow.paint(3, ColorLevel::None);
+        assert_eq!(paint.edge, " ");
+        assert_eq!(paint.bottom, "   ");
+    }
+}
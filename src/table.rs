@@ -0,0 +1,510 @@
+use std::fmt;
+
+use crate::ansi;
+use crate::color::gradient::{BorderPaint, Gradient};
+use crate::color::rgb_color::RgbColor;
+use crate::color::support::{ColorLevel, ColorSupport};
+use crate::color::text_style::TextStyle;
+use crate::color::{self, Color};
+use crate::formatting::{Alignment, Overflow, WrapMode, DEFAULT_TAB_WIDTH};
+use crate::helper;
+use crate::lines::border_chars::{self, BorderChars, Junction};
+use crate::width;
+
+/// Rows and columns of text drawn with the same lines and colors as a box.
+///
+/// Columns are as wide as their widest cell, measured in terminal columns, unless
+/// they are given a maximum width, in which case their cells wrap at word
+/// boundaries.
+/// ```
+/// use bauxite::{Alignment, Table};
+///
+/// let table = Table::new()
+///     .header(&["Name", "Qty"])
+///     .row(&["apples", "3"])
+///     .row(&["kiwis", "12"])
+///     .column_alignment(1, Alignment::Right);
+/// assert_eq!(
+///     table.to_string(),
+///     "┌────────┬─────┐\n\
+///      │ Name   │ Qty │\n\
+///      ├────────┼─────┤\n\
+///      │ apples │   3 │\n\
+///      │ kiwis  │  12 │\n\
+///      └────────┴─────┘"
+/// );
+/// ```
+pub struct Table {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    columns: Vec<Column>,
+    row_separators: bool,
+    lines: BorderChars,
+    border: BorderPaint,
+    text_style: TextStyle,
+    background: Color,
+    color_support: ColorSupport,
+}
+
+/// Settings of a single column of the table.
+#[derive(Clone, Copy)]
+struct Column {
+    alignment: Alignment,
+    max_width: Option<usize>,
+}
+
+impl Column {
+    fn new() -> Column {
+        Column {
+            alignment: Alignment::Left,
+            max_width: None,
+        }
+    }
+}
+
+impl Table {
+    /// Create an empty table
+    pub fn new() -> Table {
+        Table {
+            header: None,
+            rows: Vec::new(),
+            columns: Vec::new(),
+            row_separators: false,
+            lines: BorderChars::default(),
+            border: BorderPaint::Solid(Color::Default),
+            text_style: TextStyle::new(),
+            background: Color::Default,
+            color_support: ColorSupport::Always,
+        }
+    }
+
+    /// Set the header row, which is separated from the other rows by a line
+    pub fn header(mut self, cells: &[&str]) -> Self {
+        self.header = Some(cells.iter().map(|cell| String::from(*cell)).collect());
+        self
+    }
+
+    /// Add a row below the rows already in the table. Rows with fewer cells than
+    /// the widest row are filled with empty cells.
+    pub fn row(mut self, cells: &[&str]) -> Self {
+        self.rows
+            .push(cells.iter().map(|cell| String::from(*cell)).collect());
+        self
+    }
+
+    /// Set the alignment of the cells in the column with the given index
+    pub fn column_alignment(mut self, column: usize, align: Alignment) -> Self {
+        self.column(column).alignment = align;
+        self
+    }
+
+    /// Set the maximum width of the column with the given index, cells wider than
+    /// that are wrapped at word boundaries
+    pub fn column_max_width(mut self, column: usize, width: usize) -> Self {
+        self.column(column).max_width = Some(width);
+        self
+    }
+
+    /// Draw a line between every row, not just below the header
+    pub fn row_separators(mut self, separate: bool) -> Self {
+        self.row_separators = separate;
+        self
+    }
+
+    /// Set the type of lines to draw using [LineType](enum.LineType.html) or your own
+    /// [BorderChars](struct.BorderChars.html)
+    pub fn line_type<L: Into<BorderChars>>(mut self, line_type: L) -> Self {
+        self.lines = line_type.into();
+        self
+    }
+
+    /// Sets 8 bit color code.
+    ///
+    /// 0-7 are standard colors
+    /// 8-15 are high intensity colors
+    /// 16-231 are defined by 16 + 36 x r + 6 x g + b (0 <= r, g, b <= 5)
+    /// 232-255 are grayscale from black to white in 24 steps
+    pub fn color_8(mut self, color: u8) -> Self {
+        self.border = BorderPaint::Solid(Color::Indexed(color));
+        self
+    }
+
+    /// Basic RGB colors.
+    pub fn color_rgb(mut self, red: u8, green: u8, blue: u8) -> Self {
+        self.border = BorderPaint::Solid(Color::Rgb(RgbColor { red, green, blue }));
+        self
+    }
+
+    /// Set the line color using [Color](enum.Color.html) or anything that converts
+    /// into one.
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.border = BorderPaint::Solid(color.into());
+        self
+    }
+
+    /// Draw the lines of the table in a [Gradient](struct.Gradient.html) instead of a
+    /// single color.
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.border = BorderPaint::Gradient(gradient);
+        self
+    }
+
+    /// Fill every cell, padding included, with a [Color](enum.Color.html).
+    pub fn background<C: Into<Color>>(mut self, color: C) -> Self {
+        self.background = color.into();
+        self
+    }
+
+    /// Set the colors and attributes of the text in the cells using
+    /// [TextStyle](struct.TextStyle.html). The lines keep their own color.
+    pub fn text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    /// Set which colors are written when the table is rendered using
+    /// [ColorSupport](enum.ColorSupport.html). Defaults to `ColorSupport::Always`.
+    pub fn color_support(mut self, support: ColorSupport) -> Self {
+        self.color_support = support;
+        self
+    }
+
+    /// Settings of the column with the given index, adding columns up to it
+    fn column(&mut self, index: usize) -> &mut Column {
+        if self.columns.len() <= index {
+            self.columns.resize(index + 1, Column::new());
+        }
+        &mut self.columns[index]
+    }
+
+    /// Render the full table
+    fn render(&self) -> String {
+        let header = self.header.iter();
+        let count = header.clone().chain(&self.rows).map(Vec::len).max();
+        let count = match count {
+            Some(count) if count > 0 => count,
+            _ => return String::new(),
+        };
+        let columns = (0..count)
+            .map(|index| self.columns.get(index).copied().unwrap_or_else(Column::new))
+            .collect::<Vec<_>>();
+
+        // wrap every cell and size each column to fit its widest line
+        let rows = header
+            .chain(&self.rows)
+            .map(|row| wrap_row(row, &columns))
+            .collect::<Vec<_>>();
+        let widths = (0..count)
+            .map(|index| {
+                rows.iter()
+                    .flat_map(|row| row[index].iter())
+                    .map(|line| width::display_width(line))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let separators = if self.row_separators {
+            rows.len() - 1
+        } else {
+            usize::from(self.header.is_some() && !self.rows.is_empty())
+        };
+        let frame = Frame {
+            width: widths.iter().map(|width| width + 3).sum::<usize>() + 1,
+            height: rows.iter().map(|row| row_height(row)).sum::<usize>() + separators + 2,
+            level: self.color_support.level(),
+        };
+
+        let mut table = self.gen_rule(Junction::Top, &widths, 0, &frame);
+        let mut y = 1;
+        for (index, row) in rows.iter().enumerate() {
+            if index > 0 && (self.row_separators || (index == 1 && self.header.is_some())) {
+                table += &self.gen_rule(Junction::Cross, &widths, y, &frame);
+                y += 1;
+            }
+            for line in 0..row_height(row) {
+                table += &self.gen_line(row, line, &columns, &widths, y, &frame);
+                y += 1;
+            }
+        }
+        table += &self.gen_rule(Junction::Bottom, &widths, y, &frame);
+        table.pop();
+        table
+    }
+
+    /// Helper function to build a horizontal line across the table on row `y`. The
+    /// junction says which line it is: `Top` for the top of the table, `Bottom` for
+    /// the bottom and `Cross` for a separator between rows.
+    fn gen_rule(&self, junction: Junction, widths: &[usize], y: usize, frame: &Frame) -> String {
+        let lines = &self.lines;
+        let vertical = lines.left();
+        let (left, line, right) = match junction {
+            Junction::Top => (
+                String::from(lines.top_left()),
+                lines.top(),
+                String::from(lines.top_right()),
+            ),
+            Junction::Bottom => (
+                String::from(lines.bottom_left()),
+                lines.bottom(),
+                String::from(lines.bottom_right()),
+            ),
+            _ => (
+                border_chars::junction(lines.top(), lines.left(), Junction::Left)
+                    .unwrap_or_else(|| String::from(lines.top_left())),
+                lines.top(),
+                border_chars::junction(lines.top(), lines.right(), Junction::Right)
+                    .unwrap_or_else(|| String::from(lines.top_right())),
+            ),
+        };
+        let middle = border_chars::junction(line, vertical, junction).unwrap_or_else(|| {
+            String::from(match junction {
+                Junction::Bottom => lines.bottom_left(),
+                _ => lines.top_left(),
+            })
+        });
+
+        let rule = format!(
+            "{}{}{}",
+            left,
+            widths
+                .iter()
+                .map(|width| line.repeat(width + 2))
+                .collect::<Vec<_>>()
+                .join(&middle),
+            right
+        );
+        self.border
+            .paint(&rule, 0, y, (frame.width, frame.height), frame.level)
+            + "\n"
+    }
+
+    /// Helper function to build one line of a row of cells on row `y`
+    fn gen_line(
+        &self,
+        row: &[Vec<String>],
+        line: usize,
+        columns: &[Column],
+        widths: &[usize],
+        y: usize,
+        frame: &Frame,
+    ) -> String {
+        let size = (frame.width, frame.height);
+        let mut rendered = self
+            .border
+            .paint(self.lines.left(), 0, y, size, frame.level);
+        let mut x = 1;
+        for (index, cell) in row.iter().enumerate() {
+            let text = cell.get(line).map_or("", String::as_str);
+            let interior = format!(
+                " {} ",
+                align(
+                    &self.text_style.wrap_style(text, frame.level),
+                    width::display_width(text),
+                    widths[index],
+                    columns[index].alignment
+                )
+            );
+            rendered += &self.fill_background(interior, frame.level);
+            x += widths[index] + 2;
+            let vertical = if index + 1 == row.len() {
+                self.lines.right()
+            } else {
+                self.lines.left()
+            };
+            rendered += &self.border.paint(vertical, x, y, size, frame.level);
+            x += 1;
+        }
+        rendered + "\n"
+    }
+
+    /// Helper function to paint the background color behind a cell
+    fn fill_background(&self, interior: String, level: ColorLevel) -> String {
+        match self.background.downgrade(level).background_code() {
+            Some(code) => format!("{}{}", ansi::restyle(&interior, &code), color::RESET_CODE),
+            None => interior,
+        }
+    }
+}
+
+impl Default for Table {
+    fn default() -> Table {
+        Table::new()
+    }
+}
+
+/// Size of the table being rendered and the color level it is drawn at
+struct Frame {
+    width: usize,
+    height: usize,
+    level: ColorLevel,
+}
+
+/// Wrap the cells of a row to the maximum widths of their columns. Missing cells
+/// are empty.
+fn wrap_row(row: &[String], columns: &[Column]) -> Vec<Vec<String>> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let cell = row.get(index).map_or("", String::as_str);
+            helper::normalize_lines(
+                cell,
+                column.max_width.unwrap_or(usize::MAX),
+                0,
+                &WrapMode::Word,
+                &Overflow::Wrap,
+                &column.alignment,
+                DEFAULT_TAB_WIDTH,
+            )
+        })
+        .collect()
+}
+
+/// Number of lines the tallest cell of a row takes up, at least one.
+fn row_height(row: &[Vec<String>]) -> usize {
+    row.iter().map(Vec::len).max().unwrap_or(0).max(1)
+}
+
+/// Pad text `length` columns wide out to `width` columns.
+fn align(text: &str, length: usize, width: usize, alignment: Alignment) -> String {
+    let remaining = width - length;
+    let (left, right) = match alignment {
+        Alignment::Left | Alignment::Justify => (0, remaining),
+        Alignment::Right => (remaining, 0),
+        Alignment::Center => (remaining / 2, remaining - remaining / 2),
+    };
+    format!(
+        "{}{}{}",
+        helper::gen_whitespace(left),
+        text,
+        helper::gen_whitespace(right)
+    )
+}
+
+/// Implement fmt for Table so we can use pass a Table to `println!` for printing
+impl fmt::Display for Table {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ansi_color_codes::AnsiColorCode;
+    use crate::lines::line_type::LineType;
+
+    #[test]
+    fn test_table() {
+        let expected = "┌───┬───┐\n\
+                        │ a │ b │\n\
+                        │ c │ d │\n\
+                        └───┴───┘";
+        let table = Table::new().row(&["a", "b"]).row(&["c", "d"]);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_empty_table() {
+        assert_eq!(Table::new().to_string(), "");
+        assert_eq!(Table::new().row(&[]).to_string(), "");
+    }
+
+    #[test]
+    fn test_missing_cells() {
+        let expected = "┌───────┬─────┐\n\
+                        │ one   │ two │\n\
+                        ├───────┼─────┤\n\
+                        │ three │     │\n\
+                        └───────┴─────┘";
+        let table = Table::new().header(&["one", "two"]).row(&["three"]);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_column_alignment() {
+        let expected = "┌──────┬────────┬───────┐\n\
+                        │ left │ center │ right │\n\
+                        │ x    │   y    │     z │\n\
+                        └──────┴────────┴───────┘";
+        let table = Table::new()
+            .row(&["left", "center", "right"])
+            .row(&["x", "y", "z"])
+            .column_alignment(0, Alignment::Left)
+            .column_alignment(1, Alignment::Center)
+            .column_alignment(2, Alignment::Right);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_column_max_width() {
+        let expected = "┌──────────┬────┐\n\
+                        │ Item     │ Qt │\n\
+                        │          │ y  │\n\
+                        ├──────────┼────┤\n\
+                        │ long     │ 1  │\n\
+                        │ wrapping │    │\n\
+                        │ name     │    │\n\
+                        └──────────┴────┘";
+        let table = Table::new()
+            .header(&["Item", "Qty"])
+            .row(&["long wrapping name", "1"])
+            .column_max_width(0, 8)
+            .column_max_width(1, 2);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_wide_cells() {
+        let expected = "┌──────┬───┐\n\
+                        │ 漢字 │ a │\n\
+                        │ b    │ c │\n\
+                        └──────┴───┘";
+        let table = Table::new().row(&["漢字", "a"]).row(&["b", "c"]);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_row_separators_and_line_type() {
+        let expected = "╔═══╦═══╗\n\
+                        ║ a ║ b ║\n\
+                        ╠═══╬═══╣\n\
+                        ║ c ║ d ║\n\
+                        ╠═══╬═══╣\n\
+                        ║ e ║ f ║\n\
+                        ╚═══╩═══╝";
+        let table = Table::new()
+            .header(&["a", "b"])
+            .row(&["c", "d"])
+            .row(&["e", "f"])
+            .row_separators(true)
+            .line_type(LineType::Double);
+        assert_eq!(expected, table.to_string());
+
+        let expected = "+---+---+\n\
+                        | a | b |\n\
+                        +---+---+\n\
+                        | c | d |\n\
+                        +---+---+";
+        let table = Table::new()
+            .header(&["a", "b"])
+            .row(&["c", "d"])
+            .line_type(LineType::Ascii);
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_colored_table() {
+        let expected = "\x1B[31m┌───┐\x1B[0m\n\
+                        \x1B[31m│\x1B[0m\x1B[44m \x1B[1ma\x1B[0m\x1B[44m \x1B[0m\x1B[31m│\x1B[0m\n\
+                        \x1B[31m└───┘\x1B[0m";
+        let table = Table::new()
+            .row(&["a"])
+            .color(AnsiColorCode::Red)
+            .background(AnsiColorCode::Blue)
+            .text_style(TextStyle::new().bold())
+            .color_support(ColorSupport::Always);
+        assert_eq!(expected, table.to_string());
+    }
+}
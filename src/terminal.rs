@@ -0,0 +1,57 @@
+//! Facts about the terminal the box is printed to.
+use std::env;
+
+/// Width used when the width of the terminal can't be found.
+const DEFAULT_WIDTH: usize = 80;
+
+/// Width of the terminal in columns. The terminal on stdout or stderr is asked for
+/// its size first, then the `COLUMNS` environment variable is read.
+pub fn width() -> usize {
+    tty_width().unwrap_or_else(|| columns(env::var("COLUMNS").ok()))
+}
+
+/// Ask the terminal on stdout, or stderr when stdout is redirected, for its width.
+#[cfg(unix)]
+fn tty_width() -> Option<usize> {
+    [libc::STDOUT_FILENO, libc::STDERR_FILENO]
+        .iter()
+        .find_map(|&fd| {
+            // SAFETY: winsize is plain old data and TIOCGWINSZ only writes into it.
+            let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+            let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+            if result == 0 && size.ws_col > 0 {
+                Some(usize::from(size.ws_col))
+            } else {
+                None
+            }
+        })
+}
+
+/// Terminal sizes are only queried on unix, elsewhere `COLUMNS` is used.
+#[cfg(not(unix))]
+fn tty_width() -> Option<usize> {
+    None
+}
+
+/// Parse the value of `COLUMNS`, falling back to the default width when it is
+/// missing or isn't a positive number.
+fn columns(value: Option<String>) -> usize {
+    value
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|width| *width > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(columns(Some(String::from("120"))), 120);
+        assert_eq!(columns(Some(String::from(" 40\n"))), 40);
+        assert_eq!(columns(Some(String::from("0"))), DEFAULT_WIDTH);
+        assert_eq!(columns(Some(String::from("wide"))), DEFAULT_WIDTH);
+        assert_eq!(columns(None), DEFAULT_WIDTH);
+    }
+}
@@ -0,0 +1,124 @@
+//! Display width measurement for laying out text in a terminal.
+//!
+//! Widths are counted in terminal columns rather than bytes or chars. East Asian
+//! wide characters and emoji take two columns, combining marks take none, and
+//! grapheme clusters are never split. ANSI escape sequences take no columns and are
+//! kept whole.
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::ansi::{self, Segment};
+
+/// Number of terminal columns a single grapheme cluster occupies.
+pub fn grapheme_width(grapheme: &str) -> usize {
+    grapheme.width()
+}
+
+/// Number of terminal columns the text occupies when printed.
+pub fn display_width(text: &str) -> usize {
+    ansi::segments(text)
+        .map(|segment| match segment {
+            Segment::Text(text) => text.graphemes(true).map(grapheme_width).sum(),
+            Segment::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// Split text into a head no wider than `width` columns and the remaining tail.
+///
+/// The split always lands on a grapheme boundary and escape sequences before the
+/// split are kept in the head. If the first grapheme is wider than `width` it is
+/// still placed in the head so callers always make progress.
+pub fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    let mut offset = 0;
+    let mut any_grapheme = false;
+    for segment in ansi::segments(text) {
+        if let Segment::Text(part) = segment {
+            for (index, grapheme) in part.grapheme_indices(true) {
+                let grapheme_width = grapheme_width(grapheme);
+                if used + grapheme_width > width && any_grapheme {
+                    return text.split_at(offset + index);
+                }
+                used += grapheme_width;
+                any_grapheme = true;
+            }
+        }
+        offset += segment.as_str().len();
+    }
+    (text, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_width() {
+        assert_eq!(display_width("whatever"), 8);
+        assert_eq!(display_width(""), 0);
+    }
+
+    #[test]
+    fn test_cjk_width() {
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("a日b"), 4);
+    }
+
+    #[test]
+    fn test_combining_accent_width() {
+        // "e" followed by U+0301 COMBINING ACUTE ACCENT
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(display_width("café"), 4);
+    }
+
+    #[test]
+    fn test_emoji_zwj_width() {
+        // Family emoji built from three people joined with zero width joiners
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(display_width(family), 2);
+        assert_eq!(display_width("\u{1F600}"), 2);
+    }
+
+    #[test]
+    fn test_escape_sequences_have_no_width() {
+        assert_eq!(display_width("\x1B[31mred\x1B[0m"), 3);
+        assert_eq!(display_width("\x1B[38;2;255;0;0m日本\x1B[0m"), 4);
+        assert_eq!(
+            display_width("\x1B]8;;https://example.com\x1B\\link\x1B]8;;\x1B\\"),
+            4
+        );
+    }
+
+    #[test]
+    fn test_split_at_width() {
+        assert_eq!(split_at_width("whatever", 4), ("what", "ever"));
+        assert_eq!(split_at_width("what", 10), ("what", ""));
+    }
+
+    #[test]
+    fn test_split_never_breaks_wide_char() {
+        assert_eq!(split_at_width("日本語", 3), ("日", "本語"));
+        assert_eq!(split_at_width("日本語", 1), ("日", "本語"));
+    }
+
+    #[test]
+    fn test_split_skips_escape_sequences() {
+        assert_eq!(
+            split_at_width("\x1B[31mred\x1B[0m text", 3),
+            ("\x1B[31mred\x1B[0m", " text")
+        );
+        assert_eq!(
+            split_at_width("\x1B[1mwhatever", 4),
+            ("\x1B[1mwhat", "ever")
+        );
+    }
+
+    #[test]
+    fn test_split_keeps_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{}{}", family, family);
+        assert_eq!(split_at_width(&text, 3), (family, family));
+        assert_eq!(split_at_width("cafe\u{301}s", 4), ("cafe\u{301}", "s"));
+    }
+}
@@ -0,0 +1,284 @@
+//! Line breaking for each of the `WrapMode` strategies.
+use std::mem;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::ansi::{self, Segment};
+use crate::formatting::WrapMode;
+use crate::helper;
+use crate::width;
+
+/// Break a single line into rows no wider than `width` columns. Tabs are expanded
+/// to stops every `tab_width` columns counted from the start of the row they end up
+/// on.
+pub fn wrap_line(line: &str, width: usize, mode: &WrapMode, tab_width: usize) -> Vec<String> {
+    let expanded = helper::expand_tabs(line, tab_width);
+    if width::display_width(&expanded) <= width {
+        return vec![expanded.into_owned()];
+    }
+
+    match mode {
+        WrapMode::Char => wrap_chars(line, width, tab_width),
+        WrapMode::Word => wrap_words(line, width, false, tab_width),
+        WrapMode::WordWithHyphenation => wrap_words(line, width, true, tab_width),
+        WrapMode::NoWrap => vec![String::from(width::split_at_width(&expanded, width).0)],
+    }
+}
+
+/// Hard split the line every `width` columns. A tab that runs past the end of a row
+/// ends the row.
+fn wrap_chars(line: &str, width: usize, tab_width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    let mut column = 0;
+    for segment in ansi::segments(line) {
+        let text = match segment {
+            Segment::Text(text) => text,
+            Segment::Escape(escape) => {
+                row += escape;
+                continue;
+            }
+        };
+        for grapheme in text.graphemes(true) {
+            let grapheme_width = if grapheme == "\t" {
+                1
+            } else {
+                width::grapheme_width(grapheme)
+            };
+            if column > 0 && column + grapheme_width > width {
+                rows.push(mem::take(&mut row));
+                column = 0;
+            }
+            if grapheme == "\t" {
+                let spaces = helper::tab_stop(column, tab_width).min(width - column);
+                row += &helper::gen_whitespace(spaces);
+                column += spaces;
+            } else {
+                row += grapheme;
+                column += grapheme_width;
+            }
+        }
+    }
+    rows.push(row);
+    rows
+}
+
+/// Greedily fill rows with whole words, breaking at whitespace.
+fn wrap_words(line: &str, width: usize, hyphenate: bool, tab_width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for (space, word) in split_words(line) {
+        let space = helper::expand_tabs_from(space, current_width, tab_width);
+        let space_width = width::display_width(&space);
+        let word_width = width::display_width(word);
+
+        if current_width + space_width + word_width <= width {
+            current += &space;
+            current += word;
+            current_width += space_width + word_width;
+            continue;
+        }
+
+        if !current.is_empty() {
+            rows.push(current);
+        }
+
+        if word_width <= width {
+            current = String::from(word);
+            current_width = word_width;
+        } else {
+            let mut pieces = break_word(word, width, hyphenate);
+            current = pieces.pop().unwrap_or_default();
+            current_width = width::display_width(&current);
+            rows.append(&mut pieces);
+        }
+    }
+
+    if !current.is_empty() || rows.is_empty() {
+        rows.push(current);
+    }
+    rows
+}
+
+/// Widen the spaces between words so the row is exactly `width` columns wide.
+/// Leading whitespace is kept as indentation and rows with a single word are
+/// left untouched.
+pub fn justify_row(row: &str, width: usize) -> String {
+    let words = split_words(row);
+    if words.len() < 2 {
+        return String::from(row);
+    }
+
+    let indent = words[0].0;
+    let text_width: usize = words
+        .iter()
+        .map(|(_, word)| width::display_width(word))
+        .sum::<usize>()
+        + width::display_width(indent);
+    let gaps = words.len() - 1;
+    let spaces = width.saturating_sub(text_width).max(gaps);
+
+    let mut justified = String::from(indent);
+    for (index, (_, word)) in words.iter().enumerate() {
+        if index > 0 {
+            let gap = spaces / gaps + if index <= spaces % gaps { 1 } else { 0 };
+            justified += &" ".repeat(gap);
+        }
+        justified += word;
+    }
+    justified
+}
+
+/// Break a word that is too long to fit on a row by itself.
+fn break_word(word: &str, width: usize, hyphenate: bool) -> Vec<String> {
+    if !hyphenate || width < 2 {
+        return wrap_chars(word, width, 0);
+    }
+
+    let mut pieces = Vec::new();
+    let mut remaining = word;
+    while width::display_width(remaining) > width {
+        let (head, tail) = width::split_at_width(remaining, width - 1);
+        if width::display_width(head) < width {
+            pieces.push(format!("{}-", head));
+            remaining = tail;
+        } else {
+            // a wide grapheme leaves no room for the hyphen
+            let (head, tail) = width::split_at_width(remaining, width);
+            pieces.push(String::from(head));
+            remaining = tail;
+        }
+    }
+    pieces.push(String::from(remaining));
+    pieces
+}
+
+/// Split a line into words, each paired with the whitespace that precedes it.
+/// Trailing whitespace is dropped.
+fn split_words(line: &str) -> Vec<(&str, &str)> {
+    let mut words = Vec::new();
+    let mut remaining = line;
+    while !remaining.is_empty() {
+        let word_start = remaining
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(remaining.len());
+        let (space, rest) = remaining.split_at(word_start);
+        if rest.is_empty() {
+            break;
+        }
+        let word_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (word, rest) = rest.split_at(word_end);
+        words.push((space, word));
+        remaining = rest;
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_line_untouched() {
+        assert_eq!(wrap_line("a  b", 10, &WrapMode::Word, 8), vec!["a  b"]);
+    }
+
+    #[test]
+    fn test_char_wrap() {
+        assert_eq!(
+            wrap_line("eiusmod tempor", 10, &WrapMode::Char, 8),
+            vec!["eiusmod te", "mpor"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap() {
+        assert_eq!(
+            wrap_line("sed do eiusmod tempor incididunt", 14, &WrapMode::Word, 8),
+            vec!["sed do eiusmod", "tempor", "incididunt"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_keeps_indentation() {
+        assert_eq!(
+            wrap_line("  sed do eiusmod", 10, &WrapMode::Word, 8),
+            vec!["  sed do", "eiusmod"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_long_word() {
+        assert_eq!(
+            wrap_line("see https://example.com/path ok", 10, &WrapMode::Word, 8),
+            vec!["see", "https://ex", "ample.com/", "path ok"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_hyphenation() {
+        assert_eq!(
+            wrap_line(
+                "see https://example.com ok",
+                10,
+                &WrapMode::WordWithHyphenation,
+                8
+            ),
+            vec!["see", "https://e-", "xample.com", "ok"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_hyphenation_wide_chars() {
+        assert_eq!(
+            wrap_line("日本語日本", 2, &WrapMode::WordWithHyphenation, 8),
+            vec!["日", "本", "語", "日", "本"]
+        );
+        assert_eq!(
+            wrap_line("日本語日本", 4, &WrapMode::WordWithHyphenation, 8),
+            vec!["日-", "本-", "語-", "日本"]
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_wide_chars() {
+        assert_eq!(
+            wrap_line("日本 日本語", 6, &WrapMode::Word, 8),
+            vec!["日本", "日本語"]
+        );
+    }
+
+    #[test]
+    fn test_tab_crossing_wrap_point() {
+        assert_eq!(
+            wrap_line("ab\tc\td", 10, &WrapMode::Char, 8),
+            vec!["ab      c ", "d"]
+        );
+        assert_eq!(
+            wrap_line("name\tvalue\tmore words\there", 13, &WrapMode::Word, 8),
+            vec!["name    value", "more words", "here"]
+        );
+        assert_eq!(
+            wrap_line("abc\td\te", 12, &WrapMode::Word, 4),
+            vec!["abc d   e"]
+        );
+    }
+
+    #[test]
+    fn test_justify_row() {
+        assert_eq!(justify_row("sed do eiusmod", 18), "sed   do   eiusmod");
+        assert_eq!(justify_row("sed do eiusmod", 17), "sed   do  eiusmod");
+        assert_eq!(justify_row("  sed do", 10), "  sed   do");
+        assert_eq!(justify_row("eiusmod", 10), "eiusmod");
+    }
+
+    #[test]
+    fn test_no_wrap() {
+        assert_eq!(
+            wrap_line("sed do eiusmod", 6, &WrapMode::NoWrap, 8),
+            vec!["sed do"]
+        );
+    }
+}